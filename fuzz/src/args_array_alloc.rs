@@ -1,5 +1,5 @@
 use crate::{ ALLOC_MAX, ArrayFnCall };
-use checked_array::{ ArrayAlloc, Array };
+use checked_array::{ ArrayAlloc, ArrayAllocNew, Array };
 use arbitrary::{ Arbitrary, Result, Unstructured };
 use std::cmp;
 
@@ -174,4 +174,315 @@ impl ArrayFnCall for ArgsPopNBack {
     fn call(&mut self, array: &mut Array<Vec<u8>>) {
         let _ = array.pop_n_back(self.len);
     }
+}
+
+
+#[derive(Debug, Arbitrary)]
+pub struct ArgsInsert {
+    index: usize,
+    element: u8
+}
+impl ArrayFnCall for ArgsInsert {
+    fn call(&mut self, array: &mut Array<Vec<u8>>) {
+        let _ = array.insert(self.index, self.element);
+    }
+}
+
+
+#[derive(Debug)]
+pub struct ArgsInsertN {
+    index: usize,
+    source: Array<Vec<u8>>
+}
+impl<'a> Arbitrary<'a> for ArgsInsertN {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let index = usize::arbitrary(u)?;
+        let bytes = Vec::arbitrary(u)?;
+        Ok(Self { index, source: Array::new(bytes) })
+    }
+}
+impl ArrayFnCall for ArgsInsertN {
+    fn call(&mut self, array: &mut Array<Vec<u8>>) {
+        let _ = array.insert_n(self.index, &self.source);
+    }
+}
+
+
+#[derive(Debug, Arbitrary)]
+pub struct ArgsRemove {
+    index: usize
+}
+impl ArrayFnCall for ArgsRemove {
+    fn call(&mut self, array: &mut Array<Vec<u8>>) {
+        let _ = array.remove(self.index);
+    }
+}
+
+
+#[derive(Debug, Arbitrary)]
+pub struct ArgsRemoveN {
+    start: usize,
+    end: usize
+}
+impl ArrayFnCall for ArgsRemoveN {
+    fn call(&mut self, array: &mut Array<Vec<u8>>) {
+        let _ = array.remove_n(self.start..self.end);
+    }
+}
+
+
+#[derive(Debug, Arbitrary)]
+pub struct ArgsSwapRemove {
+    index: usize
+}
+impl ArrayFnCall for ArgsSwapRemove {
+    fn call(&mut self, array: &mut Array<Vec<u8>>) {
+        let _ = array.swap_remove(self.index);
+    }
+}
+
+
+#[derive(Debug, Arbitrary)]
+pub struct ArgsExtendFromWithin {
+    start: usize,
+    end: usize
+}
+impl ArrayFnCall for ArgsExtendFromWithin {
+    fn call(&mut self, array: &mut Array<Vec<u8>>) {
+        let _ = array.extend_from_within(self.start..self.end);
+    }
+}
+
+
+#[derive(Debug, Arbitrary)]
+pub struct ArgsDrain {
+    start: usize,
+    end: usize,
+    take: usize
+}
+impl ArrayFnCall for ArgsDrain {
+    fn call(&mut self, array: &mut Array<Vec<u8>>) {
+        if let Some(drain) = array.drain(self.start..self.end) {
+            drain.take(self.take).for_each(drop);
+        }
+    }
+}
+
+
+#[derive(Debug, Arbitrary)]
+pub struct ArgsRetain {
+    threshold: u8
+}
+impl ArrayFnCall for ArgsRetain {
+    fn call(&mut self, array: &mut Array<Vec<u8>>) {
+        let _ = array.retain(|element| *element >= self.threshold);
+    }
+}
+
+
+#[derive(Debug, Arbitrary)]
+pub struct ArgsRetainMut {
+    threshold: u8
+}
+impl ArrayFnCall for ArgsRetainMut {
+    fn call(&mut self, array: &mut Array<Vec<u8>>) {
+        let _ = array.retain_mut(|element| {
+            *element = element.wrapping_add(1);
+            *element >= self.threshold
+        });
+    }
+}
+
+
+#[derive(Debug, Arbitrary)]
+pub struct ArgsDedup;
+impl ArrayFnCall for ArgsDedup {
+    fn call(&mut self, array: &mut Array<Vec<u8>>) {
+        let _ = array.dedup();
+    }
+}
+
+
+#[derive(Debug, Arbitrary)]
+pub struct ArgsDedupBy {
+    distance: u8
+}
+impl ArrayFnCall for ArgsDedupBy {
+    fn call(&mut self, array: &mut Array<Vec<u8>>) {
+        let _ = array.dedup_by(|element, previous| element.abs_diff(*previous) <= self.distance);
+    }
+}
+
+
+#[derive(Debug, Arbitrary)]
+pub struct ArgsDedupByKey {
+    divisor: u8
+}
+impl ArrayFnCall for ArgsDedupByKey {
+    fn call(&mut self, array: &mut Array<Vec<u8>>) {
+        let _ = array.dedup_by_key(|element| element.checked_div(self.divisor));
+    }
+}
+
+
+#[derive(Debug, Arbitrary)]
+pub struct ArgsTryExtend {
+    elements: Vec<u8>
+}
+impl ArrayFnCall for ArgsTryExtend {
+    fn call(&mut self, array: &mut Array<Vec<u8>>) {
+        let _ = array.try_extend(self.elements.iter().copied());
+    }
+}
+
+
+#[derive(Debug, Arbitrary)]
+pub struct ArgsTryExtendAll {
+    elements: Vec<u8>
+}
+impl ArrayFnCall for ArgsTryExtendAll {
+    fn call(&mut self, array: &mut Array<Vec<u8>>) {
+        let _ = array.try_extend_all(self.elements.iter().copied());
+    }
+}
+
+
+#[derive(Debug, Arbitrary)]
+pub struct ArgsAllocFromIter {
+    elements: Vec<u8>
+}
+impl ArrayFnCall for ArgsAllocFromIter {
+    fn call(&mut self, array: &mut Array<Vec<u8>>) {
+        if let Ok(new) = Array::alloc_from_iter(self.elements.iter().copied()) {
+            *array = new;
+        }
+    }
+}
+
+
+#[derive(Debug, Arbitrary)]
+pub struct ArgsExtend {
+    elements: Vec<u8>
+}
+impl ArrayFnCall for ArgsExtend {
+    fn call(&mut self, array: &mut Array<Vec<u8>>) {
+        array.extend(self.elements.iter().copied());
+    }
+}
+
+
+#[derive(Debug)]
+pub struct ArgsReplaceRange {
+    start: usize,
+    end: usize,
+    elements: Array<Vec<u8>>
+}
+impl<'a> Arbitrary<'a> for ArgsReplaceRange {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let start = usize::arbitrary(u)?;
+        let end = usize::arbitrary(u)?;
+        let elements = Array::new(Vec::arbitrary(u)?);
+        Ok(Self { start, end, elements })
+    }
+}
+impl ArrayFnCall for ArgsReplaceRange {
+    fn call(&mut self, array: &mut Array<Vec<u8>>) {
+        let _ = array.replace_range(self.start..self.end, &self.elements);
+    }
+}
+
+
+#[derive(Debug, Arbitrary)]
+pub struct ArgsSplitOff {
+    at: usize
+}
+impl ArrayFnCall for ArgsSplitOff {
+    fn call(&mut self, array: &mut Array<Vec<u8>>) {
+        let _ = array.split_off(self.at);
+    }
+}
+
+
+#[derive(Debug)]
+pub struct ArgsAppend {
+    other: Array<Vec<u8>>
+}
+impl<'a> Arbitrary<'a> for ArgsAppend {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let bytes = Vec::arbitrary(u)?;
+        Ok(Self { other: Array::new(bytes) })
+    }
+}
+impl ArrayFnCall for ArgsAppend {
+    fn call(&mut self, array: &mut Array<Vec<u8>>) {
+        let _ = array.append(&mut self.other);
+    }
+}
+
+
+#[derive(Debug)]
+pub struct ArgsGrowExact {
+    len: usize
+}
+impl<'a> Arbitrary<'a> for ArgsGrowExact {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let len = cmp::min(usize::arbitrary(u)?, *ALLOC_MAX);
+        Ok(Self { len })
+    }
+}
+impl ArrayFnCall for ArgsGrowExact {
+    fn call(&mut self, array: &mut Array<Vec<u8>>) {
+        let _ = array.grow_exact(self.len);
+    }
+}
+
+
+#[derive(Debug, Arbitrary)]
+pub struct ArgsShrinkExact {
+    len: usize
+}
+impl ArrayFnCall for ArgsShrinkExact {
+    fn call(&mut self, array: &mut Array<Vec<u8>>) {
+        let _ = array.shrink_exact(self.len);
+    }
+}
+
+
+#[derive(Debug)]
+pub struct ArgsResize {
+    len: usize,
+    value: u8
+}
+impl<'a> Arbitrary<'a> for ArgsResize {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let len = cmp::min(usize::arbitrary(u)?, *ALLOC_MAX);
+        let value = u8::arbitrary(u)?;
+        Ok(Self { len, value })
+    }
+}
+impl ArrayFnCall for ArgsResize {
+    fn call(&mut self, array: &mut Array<Vec<u8>>) {
+        let _ = array.resize(self.len, self.value);
+    }
+}
+
+
+#[derive(Debug)]
+pub struct ArgsResizeWith {
+    len: usize
+}
+impl<'a> Arbitrary<'a> for ArgsResizeWith {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let len = cmp::min(usize::arbitrary(u)?, *ALLOC_MAX);
+        Ok(Self { len })
+    }
+}
+impl ArrayFnCall for ArgsResizeWith {
+    fn call(&mut self, array: &mut Array<Vec<u8>>) {
+        let mut next = 0u8;
+        let _ = array.resize_with(self.len, || {
+            next = next.wrapping_add(1);
+            next
+        });
+    }
 }
\ No newline at end of file
@@ -1,6 +1,6 @@
 use crate::ArrayFnCall;
 use checked_array::{ ArrayMut, Array };
-use arbitrary::Arbitrary;
+use arbitrary::{ Arbitrary, Result, Unstructured };
 
 
 #[derive(Debug, Arbitrary)]
@@ -53,6 +53,57 @@ impl ArrayFnCall for ArgsLastMut {
 }
 
 
+#[derive(Debug, Arbitrary)]
+pub struct ArgsSplitAtMut {
+    mid: usize
+}
+impl ArrayFnCall for ArgsSplitAtMut {
+    fn call(&mut self, array: &mut Array<Vec<u8>>) {
+        array.split_at_mut(self.mid);
+    }
+}
+
+
+#[derive(Debug, Arbitrary)]
+pub struct ArgsSplitFirstMut;
+impl ArrayFnCall for ArgsSplitFirstMut {
+    fn call(&mut self, array: &mut Array<Vec<u8>>) {
+        array.split_first_mut();
+    }
+}
+
+
+#[derive(Debug, Arbitrary)]
+pub struct ArgsSplitFirstNMut {
+    len: usize
+}
+impl ArrayFnCall for ArgsSplitFirstNMut {
+    fn call(&mut self, array: &mut Array<Vec<u8>>) {
+        array.split_first_n_mut(self.len);
+    }
+}
+
+
+#[derive(Debug, Arbitrary)]
+pub struct ArgsSplitLastMut;
+impl ArrayFnCall for ArgsSplitLastMut {
+    fn call(&mut self, array: &mut Array<Vec<u8>>) {
+        array.split_last_mut();
+    }
+}
+
+
+#[derive(Debug, Arbitrary)]
+pub struct ArgsSplitLastNMut {
+    len: usize
+}
+impl ArrayFnCall for ArgsSplitLastNMut {
+    fn call(&mut self, array: &mut Array<Vec<u8>>) {
+        array.split_last_n_mut(self.len);
+    }
+}
+
+
 #[derive(Debug, Arbitrary)]
 pub struct ArgsIterMut;
 impl ArrayFnCall for ArgsIterMut {
@@ -62,6 +113,74 @@ impl ArrayFnCall for ArgsIterMut {
 }
 
 
+#[derive(Debug)]
+pub struct ArgsCloneFromExact {
+    source: Array<Vec<u8>>
+}
+impl<'a> Arbitrary<'a> for ArgsCloneFromExact {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let bytes = Vec::arbitrary(u)?;
+        Ok(Self { source: Array::new(bytes) })
+    }
+}
+impl ArrayFnCall for ArgsCloneFromExact {
+    fn call(&mut self, array: &mut Array<Vec<u8>>) {
+        let _ = array.clone_from_exact(&self.source);
+    }
+}
+
+
+#[derive(Debug)]
+pub struct ArgsCopyFromExact {
+    source: Array<Vec<u8>>
+}
+impl<'a> Arbitrary<'a> for ArgsCopyFromExact {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let bytes = Vec::arbitrary(u)?;
+        Ok(Self { source: Array::new(bytes) })
+    }
+}
+impl ArrayFnCall for ArgsCopyFromExact {
+    fn call(&mut self, array: &mut Array<Vec<u8>>) {
+        let _ = array.copy_from_exact(&self.source);
+    }
+}
+
+
+#[derive(Debug)]
+pub struct ArgsCloneFromPrefix {
+    source: Array<Vec<u8>>
+}
+impl<'a> Arbitrary<'a> for ArgsCloneFromPrefix {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let bytes = Vec::arbitrary(u)?;
+        Ok(Self { source: Array::new(bytes) })
+    }
+}
+impl ArrayFnCall for ArgsCloneFromPrefix {
+    fn call(&mut self, array: &mut Array<Vec<u8>>) {
+        let _ = array.clone_from_prefix(&self.source);
+    }
+}
+
+
+#[derive(Debug)]
+pub struct ArgsCopyFromPrefix {
+    source: Array<Vec<u8>>
+}
+impl<'a> Arbitrary<'a> for ArgsCopyFromPrefix {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let bytes = Vec::arbitrary(u)?;
+        Ok(Self { source: Array::new(bytes) })
+    }
+}
+impl ArrayFnCall for ArgsCopyFromPrefix {
+    fn call(&mut self, array: &mut Array<Vec<u8>>) {
+        let _ = array.copy_from_prefix(&self.source);
+    }
+}
+
+
 #[derive(Debug, Arbitrary)]
 pub struct ArgsRotateLeft {
     steps: usize
@@ -90,4 +209,136 @@ impl ArrayFnCall for ArgsReverse {
     fn call(&mut self, array: &mut Array<Vec<u8>>) {
         array.reverse();
     }
+}
+
+
+#[derive(Debug, Arbitrary)]
+pub struct ArgsSwap {
+    a: usize,
+    b: usize
+}
+impl ArrayFnCall for ArgsSwap {
+    fn call(&mut self, array: &mut Array<Vec<u8>>) {
+        array.swap(self.a, self.b);
+    }
+}
+
+
+#[derive(Debug, Arbitrary)]
+pub struct ArgsSwapRanges {
+    a_start: usize,
+    a_end: usize,
+    b_start: usize,
+    b_end: usize
+}
+impl ArrayFnCall for ArgsSwapRanges {
+    fn call(&mut self, array: &mut Array<Vec<u8>>) {
+        array.swap_ranges(self.a_start..self.a_end, self.b_start..self.b_end);
+    }
+}
+
+
+#[derive(Debug, Arbitrary)]
+pub struct ArgsReplace {
+    index: usize,
+    element: u8
+}
+impl ArrayFnCall for ArgsReplace {
+    fn call(&mut self, array: &mut Array<Vec<u8>>) {
+        let _ = array.replace(self.index, self.element);
+    }
+}
+
+
+#[derive(Debug, Arbitrary)]
+pub struct ArgsTake {
+    index: usize
+}
+impl ArrayFnCall for ArgsTake {
+    fn call(&mut self, array: &mut Array<Vec<u8>>) {
+        array.take(self.index);
+    }
+}
+
+
+#[derive(Debug, Arbitrary)]
+pub struct ArgsChunksMut {
+    size: usize
+}
+impl ArrayFnCall for ArgsChunksMut {
+    fn call(&mut self, array: &mut Array<Vec<u8>>) {
+        array.chunks_mut(self.size).map(|chunks| chunks.for_each(|mut chunk| chunk.reverse()));
+    }
+}
+
+
+#[derive(Debug, Arbitrary)]
+pub struct ArgsChunksExactMut {
+    size: usize
+}
+impl ArrayFnCall for ArgsChunksExactMut {
+    fn call(&mut self, array: &mut Array<Vec<u8>>) {
+        array.chunks_exact_mut(self.size).map(|chunks| chunks.into_remainder().reverse());
+    }
+}
+
+
+#[derive(Debug, Arbitrary)]
+pub struct ArgsFill {
+    value: u8
+}
+impl ArrayFnCall for ArgsFill {
+    fn call(&mut self, array: &mut Array<Vec<u8>>) {
+        array.fill(self.value);
+    }
+}
+
+
+#[derive(Debug, Arbitrary)]
+pub struct ArgsFillWith {
+    value: u8
+}
+impl ArrayFnCall for ArgsFillWith {
+    fn call(&mut self, array: &mut Array<Vec<u8>>) {
+        array.fill_with(|| self.value);
+    }
+}
+
+
+#[derive(Debug, Arbitrary)]
+pub struct ArgsFillRange {
+    start: usize,
+    end: usize,
+    value: u8
+}
+impl ArrayFnCall for ArgsFillRange {
+    fn call(&mut self, array: &mut Array<Vec<u8>>) {
+        array.fill_range(self.start..self.end, self.value);
+    }
+}
+
+
+#[derive(Debug, Arbitrary)]
+pub struct ArgsCopyWithin {
+    start: usize,
+    end: usize,
+    dest: usize
+}
+impl ArrayFnCall for ArgsCopyWithin {
+    fn call(&mut self, array: &mut Array<Vec<u8>>) {
+        array.copy_within(self.start..self.end, self.dest);
+    }
+}
+
+
+#[derive(Debug, Arbitrary)]
+pub struct ArgsCloneWithin {
+    start: usize,
+    end: usize,
+    dest: usize
+}
+impl ArrayFnCall for ArgsCloneWithin {
+    fn call(&mut self, array: &mut Array<Vec<u8>>) {
+        array.clone_within(self.start..self.end, self.dest);
+    }
 }
\ No newline at end of file
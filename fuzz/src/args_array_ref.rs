@@ -71,6 +71,57 @@ impl ArrayFnCall for ArgsLast {
 }
 
 
+#[derive(Debug, Arbitrary)]
+pub struct ArgsSplitAt {
+    mid: usize
+}
+impl ArrayFnCall for ArgsSplitAt {
+    fn call(&mut self, array: &mut Array<Vec<u8>>) {
+        array.split_at(self.mid);
+    }
+}
+
+
+#[derive(Debug, Arbitrary)]
+pub struct ArgsSplitFirst;
+impl ArrayFnCall for ArgsSplitFirst {
+    fn call(&mut self, array: &mut Array<Vec<u8>>) {
+        array.split_first();
+    }
+}
+
+
+#[derive(Debug, Arbitrary)]
+pub struct ArgsSplitFirstN {
+    len: usize
+}
+impl ArrayFnCall for ArgsSplitFirstN {
+    fn call(&mut self, array: &mut Array<Vec<u8>>) {
+        array.split_first_n(self.len);
+    }
+}
+
+
+#[derive(Debug, Arbitrary)]
+pub struct ArgsSplitLast;
+impl ArrayFnCall for ArgsSplitLast {
+    fn call(&mut self, array: &mut Array<Vec<u8>>) {
+        array.split_last();
+    }
+}
+
+
+#[derive(Debug, Arbitrary)]
+pub struct ArgsSplitLastN {
+    len: usize
+}
+impl ArrayFnCall for ArgsSplitLastN {
+    fn call(&mut self, array: &mut Array<Vec<u8>>) {
+        array.split_last_n(self.len);
+    }
+}
+
+
 #[derive(Debug, Arbitrary)]
 pub struct ArgsIter;
 impl ArrayFnCall for ArgsIter {
@@ -94,4 +145,116 @@ impl ArrayFnCall for ArgsCloneTo {
     fn call(&mut self, array: &mut Array<Vec<u8>>) {
         let _ = array.clone_to(&mut self.target);
     }
+}
+
+
+#[derive(Debug)]
+pub struct ArgsCloneToExact {
+    target: Array<Vec<u8>>
+}
+impl<'a> Arbitrary<'a> for ArgsCloneToExact {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let bytes = Vec::arbitrary(u)?;
+        Ok(Self { target: Array::new(bytes) })
+    }
+}
+impl ArrayFnCall for ArgsCloneToExact {
+    fn call(&mut self, array: &mut Array<Vec<u8>>) {
+        let _ = array.clone_to_exact(&mut self.target);
+    }
+}
+
+
+#[derive(Debug)]
+pub struct ArgsCopyToExact {
+    target: Array<Vec<u8>>
+}
+impl<'a> Arbitrary<'a> for ArgsCopyToExact {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let bytes = Vec::arbitrary(u)?;
+        Ok(Self { target: Array::new(bytes) })
+    }
+}
+impl ArrayFnCall for ArgsCopyToExact {
+    fn call(&mut self, array: &mut Array<Vec<u8>>) {
+        let _ = array.copy_to_exact(&mut self.target);
+    }
+}
+
+
+#[derive(Debug)]
+pub struct ArgsCloneToPrefix {
+    target: Array<Vec<u8>>
+}
+impl<'a> Arbitrary<'a> for ArgsCloneToPrefix {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let bytes = Vec::arbitrary(u)?;
+        Ok(Self { target: Array::new(bytes) })
+    }
+}
+impl ArrayFnCall for ArgsCloneToPrefix {
+    fn call(&mut self, array: &mut Array<Vec<u8>>) {
+        let _ = array.clone_to_prefix(&mut self.target);
+    }
+}
+
+
+#[derive(Debug)]
+pub struct ArgsCopyToPrefix {
+    target: Array<Vec<u8>>
+}
+impl<'a> Arbitrary<'a> for ArgsCopyToPrefix {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let bytes = Vec::arbitrary(u)?;
+        Ok(Self { target: Array::new(bytes) })
+    }
+}
+impl ArrayFnCall for ArgsCopyToPrefix {
+    fn call(&mut self, array: &mut Array<Vec<u8>>) {
+        let _ = array.copy_to_prefix(&mut self.target);
+    }
+}
+
+
+#[derive(Debug, Arbitrary)]
+pub struct ArgsChunks {
+    size: usize
+}
+impl ArrayFnCall for ArgsChunks {
+    fn call(&mut self, array: &mut Array<Vec<u8>>) {
+        array.chunks(self.size).map(|chunks| chunks.count());
+    }
+}
+
+
+#[derive(Debug, Arbitrary)]
+pub struct ArgsChunksExact {
+    size: usize
+}
+impl ArrayFnCall for ArgsChunksExact {
+    fn call(&mut self, array: &mut Array<Vec<u8>>) {
+        array.chunks_exact(self.size).map(|chunks| chunks.remainder());
+    }
+}
+
+
+#[derive(Debug, Arbitrary)]
+pub struct ArgsRChunks {
+    size: usize
+}
+impl ArrayFnCall for ArgsRChunks {
+    fn call(&mut self, array: &mut Array<Vec<u8>>) {
+        array.rchunks(self.size).map(|chunks| chunks.count());
+    }
+}
+
+
+#[derive(Debug, Arbitrary)]
+pub struct ArgsWindows {
+    size: usize
+}
+impl ArrayFnCall for ArgsWindows {
+    fn call(&mut self, array: &mut Array<Vec<u8>>) {
+        array.windows(self.size).map(|windows| windows.count());
+    }
 }
\ No newline at end of file
@@ -10,19 +10,36 @@ mod args_array_alloc;
 use crate::{
     args_array_ref::{
         ArgsAsSlice, ArgsLen, ArgsIsEmpty, ArgsGet, ArgsGetN,
-        ArgsFirst, ArgsLast, ArgsIter, ArgsCloneTo
+        ArgsFirst, ArgsLast, ArgsSplitAt, ArgsSplitFirst, ArgsSplitFirstN, ArgsSplitLast, ArgsSplitLastN,
+        ArgsIter, ArgsCloneTo, ArgsCloneToExact, ArgsCopyToExact, ArgsCloneToPrefix, ArgsCopyToPrefix,
+        ArgsChunks, ArgsChunksExact, ArgsRChunks, ArgsWindows
     },
     args_array_mut::{ 
-        ArgsAsSliceMut, ArgsGetMut, ArgsGetNMut, ArgsFirstMut, ArgsLastMut, ArgsIterMut,
-        ArgsRotateLeft, ArgsRotateRight, ArgsReverse
+        ArgsAsSliceMut, ArgsGetMut, ArgsGetNMut, ArgsFirstMut, ArgsLastMut,
+        ArgsSplitAtMut, ArgsSplitFirstMut, ArgsSplitFirstNMut, ArgsSplitLastMut, ArgsSplitLastNMut, ArgsIterMut,
+        ArgsCloneFromExact, ArgsCopyFromExact, ArgsCloneFromPrefix, ArgsCopyFromPrefix,
+        ArgsRotateLeft, ArgsRotateRight, ArgsReverse,
+        ArgsSwap, ArgsSwapRanges, ArgsReplace, ArgsTake,
+        ArgsChunksMut, ArgsChunksExactMut,
+        ArgsFill, ArgsFillWith, ArgsFillRange,
+        ArgsCopyWithin, ArgsCloneWithin
     },
     args_array_alloc::{
         ArgsAllocNew, ArgsClone, ArgsGrowWith, ArgsGrow, ArgsShrink,
         ArgsPushFront, ArgsPushNFront, ArgsPushBack, ArgsPushNBack,
-        ArgsPopFront, ArgsPopNFront, ArgsPopBack, ArgsPopNBack
+        ArgsPopFront, ArgsPopNFront, ArgsPopBack, ArgsPopNBack,
+        ArgsInsert, ArgsInsertN, ArgsRemove, ArgsRemoveN, ArgsSwapRemove,
+        ArgsExtendFromWithin,
+        ArgsDrain,
+        ArgsRetain, ArgsRetainMut, ArgsDedup, ArgsDedupBy, ArgsDedupByKey,
+        ArgsTryExtend, ArgsTryExtendAll,
+        ArgsAllocFromIter, ArgsExtend,
+        ArgsReplaceRange,
+        ArgsSplitOff, ArgsAppend,
+        ArgsGrowExact, ArgsShrinkExact, ArgsResize, ArgsResizeWith
     }
 };
-use checked_array::{ ArrayAllocPanic, Array };
+use checked_array::{ ArrayAllocNewPanic, Array };
 use arbitrary::{ Arbitrary, Result, Unstructured };
 use std::{
     any, env,
@@ -102,18 +119,51 @@ impl<'a> Arbitrary<'a> for ArrayFnCalls {
             Box::new(arbitrary_call::<ArgsGetN>),
             Box::new(arbitrary_call::<ArgsFirst>),
             Box::new(arbitrary_call::<ArgsLast>),
+            Box::new(arbitrary_call::<ArgsSplitAt>),
+            Box::new(arbitrary_call::<ArgsSplitFirst>),
+            Box::new(arbitrary_call::<ArgsSplitFirstN>),
+            Box::new(arbitrary_call::<ArgsSplitLast>),
+            Box::new(arbitrary_call::<ArgsSplitLastN>),
             Box::new(arbitrary_call::<ArgsIter>),
             Box::new(arbitrary_call::<ArgsCloneTo>),
+            Box::new(arbitrary_call::<ArgsCloneToExact>),
+            Box::new(arbitrary_call::<ArgsCopyToExact>),
+            Box::new(arbitrary_call::<ArgsCloneToPrefix>),
+            Box::new(arbitrary_call::<ArgsCopyToPrefix>),
+            Box::new(arbitrary_call::<ArgsChunks>),
+            Box::new(arbitrary_call::<ArgsChunksExact>),
+            Box::new(arbitrary_call::<ArgsRChunks>),
+            Box::new(arbitrary_call::<ArgsWindows>),
                 
             Box::new(arbitrary_call::<ArgsAsSliceMut>),
             Box::new(arbitrary_call::<ArgsGetMut>),
             Box::new(arbitrary_call::<ArgsGetNMut>),
             Box::new(arbitrary_call::<ArgsFirstMut>),
             Box::new(arbitrary_call::<ArgsLastMut>),
+            Box::new(arbitrary_call::<ArgsSplitAtMut>),
+            Box::new(arbitrary_call::<ArgsSplitFirstMut>),
+            Box::new(arbitrary_call::<ArgsSplitFirstNMut>),
+            Box::new(arbitrary_call::<ArgsSplitLastMut>),
+            Box::new(arbitrary_call::<ArgsSplitLastNMut>),
             Box::new(arbitrary_call::<ArgsIterMut>),
+            Box::new(arbitrary_call::<ArgsCloneFromExact>),
+            Box::new(arbitrary_call::<ArgsCopyFromExact>),
+            Box::new(arbitrary_call::<ArgsCloneFromPrefix>),
+            Box::new(arbitrary_call::<ArgsCopyFromPrefix>),
             Box::new(arbitrary_call::<ArgsRotateLeft>),
             Box::new(arbitrary_call::<ArgsRotateRight>),
             Box::new(arbitrary_call::<ArgsReverse>),
+            Box::new(arbitrary_call::<ArgsSwap>),
+            Box::new(arbitrary_call::<ArgsSwapRanges>),
+            Box::new(arbitrary_call::<ArgsReplace>),
+            Box::new(arbitrary_call::<ArgsTake>),
+            Box::new(arbitrary_call::<ArgsChunksMut>),
+            Box::new(arbitrary_call::<ArgsChunksExactMut>),
+            Box::new(arbitrary_call::<ArgsFill>),
+            Box::new(arbitrary_call::<ArgsFillWith>),
+            Box::new(arbitrary_call::<ArgsFillRange>),
+            Box::new(arbitrary_call::<ArgsCopyWithin>),
+            Box::new(arbitrary_call::<ArgsCloneWithin>),
                 
             Box::new(arbitrary_call::<ArgsAllocNew>),
             Box::new(arbitrary_call::<ArgsClone>),
@@ -128,6 +178,29 @@ impl<'a> Arbitrary<'a> for ArrayFnCalls {
             Box::new(arbitrary_call::<ArgsPopNFront>),
             Box::new(arbitrary_call::<ArgsPopBack>),
             Box::new(arbitrary_call::<ArgsPopNBack>),
+            Box::new(arbitrary_call::<ArgsInsert>),
+            Box::new(arbitrary_call::<ArgsInsertN>),
+            Box::new(arbitrary_call::<ArgsRemove>),
+            Box::new(arbitrary_call::<ArgsRemoveN>),
+            Box::new(arbitrary_call::<ArgsSwapRemove>),
+            Box::new(arbitrary_call::<ArgsExtendFromWithin>),
+            Box::new(arbitrary_call::<ArgsDrain>),
+            Box::new(arbitrary_call::<ArgsRetain>),
+            Box::new(arbitrary_call::<ArgsRetainMut>),
+            Box::new(arbitrary_call::<ArgsDedup>),
+            Box::new(arbitrary_call::<ArgsDedupBy>),
+            Box::new(arbitrary_call::<ArgsDedupByKey>),
+            Box::new(arbitrary_call::<ArgsTryExtend>),
+            Box::new(arbitrary_call::<ArgsTryExtendAll>),
+            Box::new(arbitrary_call::<ArgsAllocFromIter>),
+            Box::new(arbitrary_call::<ArgsExtend>),
+            Box::new(arbitrary_call::<ArgsReplaceRange>),
+            Box::new(arbitrary_call::<ArgsSplitOff>),
+            Box::new(arbitrary_call::<ArgsAppend>),
+            Box::new(arbitrary_call::<ArgsGrowExact>),
+            Box::new(arbitrary_call::<ArgsShrinkExact>),
+            Box::new(arbitrary_call::<ArgsResize>),
+            Box::new(arbitrary_call::<ArgsResizeWith>),
         ];
 
         // Construct the calls in an arbitrary order
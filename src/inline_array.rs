@@ -0,0 +1,109 @@
+use crate::{
+    misc::BufferTooSmall, traits::{ CanAlloc, CanAllocNew },
+    preallocated_uninit::PreallocatedUninit,
+    std::{
+        mem::MaybeUninit,
+        fmt::{ self, Debug, Formatter },
+        slice::{ Iter as SliceIter, IterMut as SliceIterMut }
+    }
+};
+
+
+/// A wrapper that implements `CanAlloc` over an inline array with a fixed capacity of `N` elements
+///
+/// __Discussion:__ In contrast to `Preallocated`, this type owns its storage and can thus create new instances out of
+/// nothing, so it also implements `CanAllocNew`.
+pub struct InlineArray<T, const N: usize> {
+    /// The underlying storage
+    storage: PreallocatedUninit<T, [MaybeUninit<T>; N]>
+}
+impl<T, const N: usize> InlineArray<T, N> {
+    /// Creates a new empty `InlineArray` instance
+    pub fn new() -> Self {
+        // Safety: An array of `MaybeUninit` does not require initialization
+        let buffer = unsafe { MaybeUninit::<[MaybeUninit<T>; N]>::uninit().assume_init() };
+        Self { storage: PreallocatedUninit::new(buffer) }
+    }
+
+    /// The amount of elements `self` can hold
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+}
+impl<T, const N: usize> AsRef<[T]> for InlineArray<T, N> {
+    fn as_ref(&self) -> &[T] {
+        self.storage.as_ref()
+    }
+}
+impl<T, const N: usize> AsMut<[T]> for InlineArray<T, N> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self.storage.as_mut()
+    }
+}
+impl<T, const N: usize> CanAlloc<T> for InlineArray<T, N> {
+    type Error = BufferTooSmall;
+
+    fn try_push(&mut self, element: T) -> Result<(), (Self::Error, T)> {
+        self.storage.try_push(element)
+    }
+    fn pop(&mut self) -> Result<Option<T>, Self::Error> {
+        self.storage.pop()
+    }
+
+    fn reserve(&mut self, additional: usize) -> Result<(), Self::Error> {
+        self.storage.reserve(additional)
+    }
+    fn extend_from_slice(&mut self, elements: &[T]) -> Result<(), Self::Error> where T: Clone {
+        self.storage.extend_from_slice(elements)
+    }
+    fn truncate(&mut self, len: usize) -> Result<(), Self::Error> {
+        self.storage.truncate(len)
+    }
+}
+impl<T, const N: usize> CanAllocNew<T> for InlineArray<T, N> {
+    fn alloc_new() -> Result<Self, Self::Error> {
+        Ok(Self::new())
+    }
+}
+// - MARK: Propagate common trait implementations
+impl<T, const N: usize> Debug for InlineArray<T, N> where T: Debug {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+impl<T, const N: usize> Default for InlineArray<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<T, const N: usize> Clone for InlineArray<T, N> where T: Clone {
+    fn clone(&self) -> Self {
+        let mut this = Self::new();
+        this.extend_from_slice(self.as_ref()).expect("Failed to clone into array with same capacity?!");
+        this
+    }
+}
+impl<T, const N: usize> PartialEq for InlineArray<T, N> where T: PartialEq {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_ref().eq(other.as_ref())
+    }
+}
+impl<T, const N: usize> Eq for InlineArray<T, N> where T: Eq {
+    /* Eq is a marker trait; no implementation required */
+}
+impl<'a, T, const N: usize> IntoIterator for &'a InlineArray<T, N> {
+    type Item = &'a T;
+    type IntoIter = SliceIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_ref().iter()
+    }
+}
+impl<'a, T, const N: usize> IntoIterator for &'a mut InlineArray<T, N> {
+    type Item = &'a mut T;
+    type IntoIter = SliceIterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_mut().iter_mut()
+    }
+}
\ No newline at end of file
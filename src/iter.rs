@@ -0,0 +1,271 @@
+use crate::{
+    wrapper::Array, traits::ArrayAlloc,
+    std::{
+        iter::FusedIterator, marker::PhantomData,
+        slice::{
+            Chunks as SliceChunks, ChunksExact as SliceChunksExact, RChunks as SliceRChunks, Windows as SliceWindows,
+            ChunksMut as SliceChunksMut, ChunksExactMut as SliceChunksExactMut
+        }
+    }
+};
+
+
+/// An iterator over non-overlapping subranges of `size` elements, starting at the front
+#[derive(Debug)]
+pub struct Chunks<'a, T> {
+    /// The underlying slice iterator
+    inner: SliceChunks<'a, T>
+}
+impl<'a, T> Chunks<'a, T> {
+    /// Wraps a slice iterator
+    pub(in crate) fn new(inner: SliceChunks<'a, T>) -> Self {
+        Self { inner }
+    }
+}
+impl<'a, T> Iterator for Chunks<'a, T> {
+    type Item = Array<&'a [T]>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(Array::new)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+impl<'a, T> DoubleEndedIterator for Chunks<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(Array::new)
+    }
+}
+impl<'a, T> ExactSizeIterator for Chunks<'a, T> {
+    /* The length is provided by `size_hint` */
+}
+impl<'a, T> FusedIterator for Chunks<'a, T> {
+    /* The underlying slice iterator is fused */
+}
+
+/// An iterator over non-overlapping subranges of exactly `size` elements, starting at the front
+#[derive(Debug)]
+pub struct ChunksExact<'a, T> {
+    /// The underlying slice iterator
+    inner: SliceChunksExact<'a, T>
+}
+impl<'a, T> ChunksExact<'a, T> {
+    /// Wraps a slice iterator
+    pub(in crate) fn new(inner: SliceChunksExact<'a, T>) -> Self {
+        Self { inner }
+    }
+
+    /// The remaining elements which do not form a complete chunk
+    pub fn remainder(&self) -> Array<&'a [T]> {
+        Array::new(self.inner.remainder())
+    }
+}
+impl<'a, T> Iterator for ChunksExact<'a, T> {
+    type Item = Array<&'a [T]>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(Array::new)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+impl<'a, T> DoubleEndedIterator for ChunksExact<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(Array::new)
+    }
+}
+impl<'a, T> ExactSizeIterator for ChunksExact<'a, T> {
+    /* The length is provided by `size_hint` */
+}
+impl<'a, T> FusedIterator for ChunksExact<'a, T> {
+    /* The underlying slice iterator is fused */
+}
+
+/// An iterator over non-overlapping subranges of `size` elements, starting at the back
+#[derive(Debug)]
+pub struct RChunks<'a, T> {
+    /// The underlying slice iterator
+    inner: SliceRChunks<'a, T>
+}
+impl<'a, T> RChunks<'a, T> {
+    /// Wraps a slice iterator
+    pub(in crate) fn new(inner: SliceRChunks<'a, T>) -> Self {
+        Self { inner }
+    }
+}
+impl<'a, T> Iterator for RChunks<'a, T> {
+    type Item = Array<&'a [T]>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(Array::new)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+impl<'a, T> DoubleEndedIterator for RChunks<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(Array::new)
+    }
+}
+impl<'a, T> ExactSizeIterator for RChunks<'a, T> {
+    /* The length is provided by `size_hint` */
+}
+impl<'a, T> FusedIterator for RChunks<'a, T> {
+    /* The underlying slice iterator is fused */
+}
+
+/// An iterator over overlapping subranges of `size` elements
+#[derive(Debug)]
+pub struct Windows<'a, T> {
+    /// The underlying slice iterator
+    inner: SliceWindows<'a, T>
+}
+impl<'a, T> Windows<'a, T> {
+    /// Wraps a slice iterator
+    pub(in crate) fn new(inner: SliceWindows<'a, T>) -> Self {
+        Self { inner }
+    }
+}
+impl<'a, T> Iterator for Windows<'a, T> {
+    type Item = Array<&'a [T]>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(Array::new)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+impl<'a, T> DoubleEndedIterator for Windows<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(Array::new)
+    }
+}
+impl<'a, T> ExactSizeIterator for Windows<'a, T> {
+    /* The length is provided by `size_hint` */
+}
+impl<'a, T> FusedIterator for Windows<'a, T> {
+    /* The underlying slice iterator is fused */
+}
+
+/// An iterator over non-overlapping mutable subranges of `size` elements, starting at the front
+#[derive(Debug)]
+pub struct ChunksMut<'a, T> {
+    /// The underlying slice iterator
+    inner: SliceChunksMut<'a, T>
+}
+impl<'a, T> ChunksMut<'a, T> {
+    /// Wraps a slice iterator
+    pub(in crate) fn new(inner: SliceChunksMut<'a, T>) -> Self {
+        Self { inner }
+    }
+}
+impl<'a, T> Iterator for ChunksMut<'a, T> {
+    type Item = Array<&'a mut [T]>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(Array::new)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+impl<'a, T> DoubleEndedIterator for ChunksMut<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(Array::new)
+    }
+}
+impl<'a, T> ExactSizeIterator for ChunksMut<'a, T> {
+    /* The length is provided by `size_hint` */
+}
+impl<'a, T> FusedIterator for ChunksMut<'a, T> {
+    /* The underlying slice iterator is fused */
+}
+
+/// An iterator over non-overlapping mutable subranges of exactly `size` elements, starting at the front
+#[derive(Debug)]
+pub struct ChunksExactMut<'a, T> {
+    /// The underlying slice iterator
+    inner: SliceChunksExactMut<'a, T>
+}
+impl<'a, T> ChunksExactMut<'a, T> {
+    /// Wraps a slice iterator
+    pub(in crate) fn new(inner: SliceChunksExactMut<'a, T>) -> Self {
+        Self { inner }
+    }
+
+    /// The remaining elements which do not form a complete chunk
+    pub fn into_remainder(self) -> Array<&'a mut [T]> {
+        Array::new(self.inner.into_remainder())
+    }
+}
+impl<'a, T> Iterator for ChunksExactMut<'a, T> {
+    type Item = Array<&'a mut [T]>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(Array::new)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+impl<'a, T> DoubleEndedIterator for ChunksExactMut<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(Array::new)
+    }
+}
+impl<'a, T> ExactSizeIterator for ChunksExactMut<'a, T> {
+    /* The length is provided by `size_hint` */
+}
+impl<'a, T> FusedIterator for ChunksExactMut<'a, T> {
+    /* The underlying slice iterator is fused */
+}
+
+
+/// An iterator that removes a range of elements from an array
+///
+/// __Discussion:__ The remaining elements are already shifted into place when the iterator is created; the drained
+/// elements are moved to the end of the array and are popped one by one. If the iterator is dropped, the remaining
+/// drained elements are popped and dropped too. If the iterator is leaked, the remaining drained elements stay at the
+/// end of the array in reverse order.
+pub struct Drain<'a, T, A> where A: ArrayAlloc<T> {
+    /// The underlying array
+    array: &'a mut A,
+    /// The amount of drained elements that have not been popped yet
+    remaining: usize,
+    /// The element type
+    _element: PhantomData<T>
+}
+impl<'a, T, A> Drain<'a, T, A> where A: ArrayAlloc<T> {
+    /// Creates a new iterator that pops the last `remaining` elements from `array`
+    ///
+    ///  - Note: The caller must ensure that the drained elements are at the end of `array` in reverse order
+    pub(in crate) fn new(array: &'a mut A, remaining: usize) -> Self {
+        Self { array, remaining, _element: PhantomData }
+    }
+}
+impl<'a, T, A> Iterator for Drain<'a, T, A> where A: ArrayAlloc<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.remaining = self.remaining.checked_sub(1)?;
+        self.array.pop_back().ok().flatten()
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+impl<'a, T, A> ExactSizeIterator for Drain<'a, T, A> where A: ArrayAlloc<T> {
+    /* The length is provided by `size_hint` */
+}
+impl<'a, T, A> FusedIterator for Drain<'a, T, A> where A: ArrayAlloc<T> {
+    /* `remaining` never increases */
+}
+impl<'a, T, A> Drop for Drain<'a, T, A> where A: ArrayAlloc<T> {
+    fn drop(&mut self) {
+        self.for_each(drop);
+    }
+}
\ No newline at end of file
@@ -1,12 +1,19 @@
-#![cfg_attr(feature = "no_std", no_std)]
+#![no_std]
 
-/// Reexports core or std as `crate::std`
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std as libstd;
+
+/// Reexports core and the available parts of alloc and std as `crate::std`
 pub(in crate) mod std {
-    #[cfg(not(feature = "std"))]
     pub use core::*;
 
+    #[cfg(feature = "alloc")]
+    pub use ::alloc::{ boxed, collections, vec };
+
     #[cfg(feature = "std")]
-    pub use std::*;
+    pub use ::libstd::error;
 }
 
 
@@ -15,11 +22,42 @@ pub mod misc;
 
 /// A wrapper that implements `CanAlloc` over a preallocated buffer
 mod preallocated;
-pub use preallocated::Preallocated;
+pub use preallocated::{ Preallocated, BufferElement };
+
+/// A wrapper that implements `CanAlloc` over a preallocated buffer of possibly uninitialized elements
+mod preallocated_uninit;
+pub use preallocated_uninit::{ PreallocatedUninit, UninitBuffer };
+
+/// A wrapper that implements `CanAlloc` over an inline array with a fixed capacity
+mod inline_array;
+pub use inline_array::InlineArray;
+
+/// A wrapper that implements a bounded FIFO over a preallocated buffer
+mod ring_buffer;
+pub use ring_buffer::{ RingBuffer, FullPolicy };
+
+/// A `Vec` wrapper that implements `CanAlloc` with fallible allocations
+#[cfg(feature = "alloc")]
+mod tryvec;
+#[cfg(feature = "alloc")]
+pub use tryvec::TryVec;
+
+/// A wrapper that implements `CanAlloc` over an inline array which spills to the heap if necessary
+#[cfg(feature = "alloc")]
+mod small_array;
+#[cfg(feature = "alloc")]
+pub use small_array::SmallArray;
 
 /// Byte traits exposing a checked API only
 mod traits;
-pub use crate::traits::{ ArrayRef, ArrayMut, ArrayAlloc, ArrayAllocPanic };
+pub use crate::traits::{
+    ArrayRef, ArrayMut, ArrayAlloc, ArrayAllocNew, ArrayAllocPanic, ArrayAllocNewPanic, ArraySplit, ArraySplitMut,
+    ArraySegmentedRef, ArraySegmentedMut, ArraySegmentedAlloc, ArraySegmentedAllocPanic
+};
+
+/// Iterators that yield `Array` views
+mod iter;
+pub use crate::iter::{ Chunks, ChunksExact, RChunks, Windows, ChunksMut, ChunksExactMut, Drain };
 
 /// A generic wrapper that implements the `Bytes*`-traits for the underlying element
 mod wrapper;
@@ -1,5 +1,6 @@
 use crate::std::{
-    fmt::{ self, Display, Formatter },
+    convert::Infallible,
+    fmt::{ self, Debug, Display, Formatter },
     ops::{ Range, RangeBounds, Bound }
 };
 #[cfg(feature = "std")]
@@ -7,21 +8,149 @@ use crate::std::error::Error;
 
 
 /// An error indicating that a buffer is too small
+///
+///  - Note: An amount of needed elements that overflows a `usize` is reported as `usize::MAX`
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct BufferTooSmall;
+pub struct BufferTooSmall {
+    /// The amount of elements needed
+    pub needed: usize,
+    /// The amount of elements available
+    pub available: usize
+}
 impl Display for BufferTooSmall {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "Buffer is too small")
+        write!(f, "Buffer is too small ({} elements needed, {} available)", self.needed, self.available)
     }
 }
 #[cfg(feature = "std")]
 impl Error for BufferTooSmall {}
 
 
+/// An error indicating that two buffers have different lengths
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LengthMismatch;
+impl Display for LengthMismatch {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "Buffer lengths are not equal")
+    }
+}
+#[cfg(feature = "std")]
+impl Error for LengthMismatch {}
+
+
+/// An error indicating that an allocation has failed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AllocError {
+    /// The amount of elements that could not be allocated
+    pub requested: usize
+}
+impl Display for AllocError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "Failed to allocate memory for {} elements", self.requested)
+    }
+}
+#[cfg(feature = "std")]
+impl Error for AllocError {}
+
+
 /// An error which indicates that an implementation will always panic instead of returning an error
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[non_exhaustive]
 pub enum WillPanic {}
+impl Display for WillPanic {
+    fn fmt(&self, _f: &mut Formatter) -> fmt::Result {
+        match *self {}
+    }
+}
+#[cfg(feature = "std")]
+impl Error for WillPanic {}
+
+
+/// A structured error which describes why an array operation has failed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ArrayError<E = Infallible> {
+    /// An index is out of bounds
+    OutOfBounds {
+        /// The invalid index
+        index: usize,
+        /// The length of the array
+        len: usize
+    },
+    /// A range is either inverted or out of bounds
+    ///
+    ///  - Note: Bounds that overflow a `usize` are reported as `usize::MAX`
+    InvalidRange {
+        /// The absolute start of the range
+        start: usize,
+        /// The absolute end of the range
+        end: usize,
+        /// The length of the array
+        len: usize
+    },
+    /// A buffer is too small
+    BufferTooSmall {
+        /// The amount of elements needed
+        needed: usize,
+        /// The amount of elements available
+        available: usize
+    },
+    /// A requested length is on the wrong side of the current length
+    InvalidLength {
+        /// The requested length
+        requested: usize,
+        /// The length of the array
+        len: usize
+    },
+    /// Two buffers have different lengths
+    LengthMismatch {
+        /// The expected length
+        expected: usize,
+        /// The actual length
+        found: usize
+    },
+    /// An alloc related error
+    Alloc(E)
+}
+impl<E> ArrayError<E> {
+    /// Maps the alloc related error using `f` and keeps all other errors
+    pub fn map_alloc<F>(self, f: impl FnOnce(E) -> F) -> ArrayError<F> {
+        match self {
+            Self::OutOfBounds { index, len } => ArrayError::OutOfBounds { index, len },
+            Self::InvalidRange { start, end, len } => ArrayError::InvalidRange { start, end, len },
+            Self::BufferTooSmall { needed, available } => ArrayError::BufferTooSmall { needed, available },
+            Self::InvalidLength { requested, len } => ArrayError::InvalidLength { requested, len },
+            Self::LengthMismatch { expected, found } => ArrayError::LengthMismatch { expected, found },
+            Self::Alloc(error) => ArrayError::Alloc(f(error))
+        }
+    }
+}
+impl<E> From<E> for ArrayError<E> {
+    fn from(error: E) -> Self {
+        Self::Alloc(error)
+    }
+}
+impl<E> Display for ArrayError<E> where E: Display {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::OutOfBounds { index, len } => write!(f, "Index {} is out of bounds for length {}", index, len),
+            Self::InvalidRange { start, end, len } => {
+                write!(f, "Range {}..{} is invalid for length {}", start, end, len)
+            },
+            Self::BufferTooSmall { needed, available } => {
+                write!(f, "Buffer is too small ({} elements needed, {} available)", needed, available)
+            },
+            Self::InvalidLength { requested, len } => {
+                write!(f, "Length {} is invalid for an array with length {}", requested, len)
+            },
+            Self::LengthMismatch { expected, found } => {
+                write!(f, "Buffer lengths are not equal (expected {}, found {})", expected, found)
+            },
+            Self::Alloc(error) => write!(f, "Allocation error: {}", error)
+        }
+    }
+}
+#[cfg(feature = "std")]
+impl<E> Error for ArrayError<E> where E: Debug + Display {}
 
 
 /// An extension to the range bounds trait
@@ -49,4 +178,21 @@ impl<T> RangeBoundsExt<usize> for T where T: RangeBounds<usize> {
             false => None
         }
     }
+}
+
+
+/// Resolves `range` against an array with `len` elements
+pub(in crate) fn resolve_range<R, E>(range: R, len: usize) -> Result<Range<usize>, ArrayError<E>>
+    where R: RangeBounds<usize>
+{
+    // Translate the bounds separately if the range is invalid so that the error can report both of them
+    let bounds = (range.start_bound().cloned(), range.end_bound().cloned());
+    match bounds.into_absolute(0, len) {
+        Some(range) if range.end <= len => Ok(range),
+        _ => Err(ArrayError::InvalidRange {
+            start: (bounds.0, Bound::Unbounded).into_absolute(0, usize::MAX).map_or(usize::MAX, |range| range.start),
+            end: (Bound::Unbounded, bounds.1).into_absolute(0, len).map_or(usize::MAX, |range| range.end),
+            len
+        })
+    }
 }
\ No newline at end of file
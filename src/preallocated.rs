@@ -1,14 +1,49 @@
 use crate::{
     misc::BufferTooSmall, traits::CanAlloc,
-    std::{ mem, cmp }
+    std::{
+        mem, cmp, ops::Range,
+        slice::{ Iter as SliceIter, IterMut as SliceIterMut }
+    }
 };
+#[cfg(feature = "alloc")]
+use crate::std::{ boxed::Box, vec::Vec };
+
+
+/// A buffer with a known element type
+///
+/// __Discussion:__ This trait is only needed to iterate over a `Preallocated` by reference; implement it for your own
+/// buffer type if you need `for element in &preallocated`.
+pub trait BufferElement {
+    /// The element type
+    type Element;
+}
+impl<T, const N: usize> BufferElement for [T; N] {
+    type Element = T;
+}
+impl<T> BufferElement for &mut [T] {
+    type Element = T;
+}
+#[cfg(feature = "alloc")]
+impl<T> BufferElement for Box<[T]> {
+    type Element = T;
+}
+#[cfg(feature = "alloc")]
+impl<T> BufferElement for Vec<T> {
+    type Element = T;
+}
 
 
 /// A wrapper that implements `CanAlloc` over a preallocated buffer
+///
+/// __Discussion:__ The used elements are tracked with a head offset and an end offset, so the elements can be consumed
+/// from the front in O(1) without shifting the remaining elements. The elements are only moved back to the start of the
+/// buffer if the unused space at the end is too small for a push, or if the buffer is returned via `into_compacted`.
 pub struct Preallocated<Buffer> {
     /// The wrapped buffer
     buffer: Buffer,
-    /// The amount of bytes used
+    /// The start of the used elements
+    head: usize,
+    /// The end of the used elements
     used: usize
 }
 impl<Buffer> Preallocated<Buffer> {
@@ -21,74 +56,216 @@ impl<Buffer> Preallocated<Buffer> {
     /// __Discussion:__ If `used` is greated than `buffer.len()`, it will be silently capped to `buffer.len()` wherever
     /// necessary
     pub const fn new_with_used(buffer: Buffer, used: usize) -> Self {
-        Self { buffer, used }
+        Self { buffer, head: 0, used }
     }
 
     /// Returns the wrapped buffer
+    ///
+    ///  - Note: If elements have been popped from the front, the used elements do not start at the beginning of the
+    ///    buffer but at the head offset; use `into_compacted` to move them to the start first
     #[inline(always)]
     pub fn into_inner(self) -> Buffer {
         self.buffer
     }
+    /// Moves the used elements to the start of the buffer and returns the wrapped buffer
+    pub fn into_compacted<T>(mut self) -> Buffer where Buffer: AsMut<[T]> {
+        self.compact();
+        self.buffer
+    }
+
+    /// Moves the used elements to the start of the buffer
+    fn compact<T>(&mut self) where Buffer: AsMut<[T]> {
+        let buffer_len = self.buffer.as_mut().len();
+        self.cap(buffer_len);
+        let buffer = self.buffer.as_mut();
+
+        buffer[..self.used].rotate_left(self.head);
+        self.used -= self.head;
+        self.head = 0;
+    }
+    /// Caps `head` and `used` to the buffer size because we cannot ensure that the buffer has not been resized
+    /// somewhere else
+    fn cap(&mut self, buffer_len: usize) {
+        self.used = cmp::min(self.used, buffer_len);
+        self.head = cmp::min(self.head, self.used);
+    }
+    /// Ensures that there are at least `additional` unused elements at the end of the buffer and moves the used
+    /// elements to the start of the buffer if necessary
+    fn make_room<T>(&mut self, additional: usize) -> Result<(), BufferTooSmall> where Buffer: AsMut<[T]> {
+        let buffer_len = self.buffer.as_mut().len();
+        self.cap(buffer_len);
+
+        // Ensure that the buffer is large enough
+        let needed = (self.used - self.head).saturating_add(additional);
+        if needed > buffer_len {
+            Err(BufferTooSmall { needed, available: buffer_len })?;
+        }
+
+        // Move the elements to the start if the unused space at the end is too small
+        if buffer_len - self.used < additional {
+            self.compact();
+        }
+        Ok(())
+    }
 }
 impl<Buffer, T> AsRef<[T]> for Preallocated<Buffer> where Buffer: AsRef<[T]> {
     fn as_ref(&self) -> &[T] {
-        // Cap `used` to the buffer size because we cannot ensure that the buffer has not been resized somewhere else
+        // Cap `head` and `used` to the buffer size because we cannot ensure that the buffer has not been resized
+        // somewhere else
         let buffer = self.buffer.as_ref();
         let used = cmp::min(self.used, buffer.len());
-        
+        let head = cmp::min(self.head, used);
+
         // Take the used subslice
-        &buffer[..used]
+        &buffer[head..used]
     }
 }
 impl<Buffer, T> AsMut<[T]> for Preallocated<Buffer> where Buffer: AsMut<[T]> {
     fn as_mut(&mut self) -> &mut [T] {
-        // Cap `used` to the buffer size because we cannot ensure that the buffer has not been resized somewhere else
+        // Cap `head` and `used` to the buffer size because we cannot ensure that the buffer has not been resized
+        // somewhere else
         let buffer = self.buffer.as_mut();
         let used = cmp::min(self.used, buffer.len());
-        
+        let head = cmp::min(self.head, used);
+
         // Take the used subslice
-        &mut buffer[..used]
+        &mut buffer[head..used]
     }
 }
 impl<Buffer, T> CanAlloc<T> for Preallocated<Buffer> where Buffer: AsRef<[T]> + AsMut<[T]>, T: Default {
     type Error = BufferTooSmall;
 
-    /// __Warning:__ This function will always fail because we cannot create a preallocated out of nothing
-    fn alloc_new() -> Result<Self, Self::Error> {
-        Err(BufferTooSmall)
-    }
-
-    fn push(&mut self, element: T) -> Result<(), Self::Error> {
-        // Cap `used` to the buffer size because we cannot ensure that the buffer has not been resized somewhere else
-        let buffer = self.buffer.as_mut();
-        self.used = cmp::min(self.used, buffer.len());
-
+    fn try_push(&mut self, element: T) -> Result<(), (Self::Error, T)> {
         // Ensure that the buffer is not full
-        let next = match self.used.checked_add(1) {
-            Some(next) if next <= buffer.len() => next,
-            _ => Err(BufferTooSmall)?
-        };
+        if let Err(e) = self.make_room(1) {
+            return Err((e, element));
+        }
 
         // Append the element
-        buffer[self.used] = element;
-        self.used = next;
+        self.buffer.as_mut()[self.used] = element;
+        self.used += 1;
         Ok(())
     }
-
     fn pop(&mut self) -> Result<Option<T>, Self::Error> {
-        // Cap `used` to the buffer size because we cannot ensure that the buffer has not been resized somewhere else
+        let buffer_len = self.buffer.as_mut().len();
+        self.cap(buffer_len);
         let buffer = self.buffer.as_mut();
-        self.used = cmp::min(self.used, buffer.len());
 
         // Validate that the used buffer is not empty
-        let last = match self.used.checked_sub(1) {
-            Some(last) => last,
-            None => return Ok(None)
-        };
+        if self.head == self.used {
+            return Ok(None);
+        }
 
         // Take the last element and replace it with a default element
-        let element = mem::replace(&mut buffer[last], T::default());
-        self.used = last;
+        self.used -= 1;
+        let element = mem::take(&mut buffer[self.used]);
+        Ok(Some(element))
+    }
+    fn try_push_front(&mut self, element: T) -> Result<(), (Self::Error, T)> {
+        let buffer_len = self.buffer.as_mut().len();
+        self.cap(buffer_len);
+        let buffer = self.buffer.as_mut();
+
+        // Prepend the element in front of the head if possible
+        if self.head > 0 {
+            self.head -= 1;
+            buffer[self.head] = element;
+            return Ok(());
+        }
+
+        // Append the element and rotate it into place
+        self.try_push(element)?;
+        self.as_mut().rotate_right(1);
+        Ok(())
+    }
+    fn pop_front(&mut self) -> Result<Option<T>, Self::Error> {
+        let buffer_len = self.buffer.as_mut().len();
+        self.cap(buffer_len);
+        let buffer = self.buffer.as_mut();
+
+        // Validate that the used buffer is not empty
+        if self.head == self.used {
+            return Ok(None);
+        }
+
+        // Take the first element and replace it with a default element
+        let element = mem::take(&mut buffer[self.head]);
+        self.head += 1;
+
+        // Reset the offsets if the buffer is empty so that the whole buffer is available again
+        if self.head == self.used {
+            self.head = 0;
+            self.used = 0;
+        }
         Ok(Some(element))
     }
+
+    fn reserve(&mut self, additional: usize) -> Result<(), Self::Error> {
+        // Cap `head` and `used` to the buffer size because we cannot ensure that the buffer has not been resized
+        // somewhere else
+        let buffer = self.buffer.as_ref();
+        let used = cmp::min(self.used, buffer.len());
+        let head = cmp::min(self.head, used);
+
+        // Ensure that the buffer is large enough
+        let needed = (used - head).saturating_add(additional);
+        match needed <= buffer.len() {
+            true => Ok(()),
+            false => Err(BufferTooSmall { needed, available: buffer.len() })
+        }
+    }
+    fn extend_from_slice(&mut self, elements: &[T]) -> Result<(), Self::Error> where T: Clone {
+        // Ensure that the remaining buffer is large enough
+        self.make_room(elements.len())?;
+        let next = self.used + elements.len();
+
+        // Clone the elements into the buffer
+        self.buffer.as_mut()[self.used..next].clone_from_slice(elements);
+        self.used = next;
+        Ok(())
+    }
+    fn extend_from_within(&mut self, range: Range<usize>) -> Result<(), Self::Error> where T: Clone {
+        // Ensure that the remaining buffer is large enough
+        self.make_room(range.len())?;
+        let (head, count) = (self.head, range.len());
+
+        // Clone the elements into the unused part of the buffer
+        let (used, unused) = self.buffer.as_mut().split_at_mut(self.used);
+        unused[..count].clone_from_slice(&used[head + range.start .. head + range.end]);
+        self.used += count;
+        Ok(())
+    }
+    fn truncate(&mut self, len: usize) -> Result<(), Self::Error> {
+        let buffer_len = self.buffer.as_mut().len();
+        self.cap(buffer_len);
+        let buffer = self.buffer.as_mut();
+
+        // Replace the truncated elements with default elements
+        if len < self.used - self.head {
+            let end = self.head + len;
+            buffer[end..self.used].fill_with(T::default);
+            self.used = end;
+        }
+        Ok(())
+    }
+}
+impl<'a, Buffer> IntoIterator for &'a Preallocated<Buffer>
+    where Buffer: BufferElement + AsRef<[Buffer::Element]>, Buffer::Element: 'a
+{
+    type Item = &'a Buffer::Element;
+    type IntoIter = SliceIter<'a, Buffer::Element>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_ref().iter()
+    }
+}
+impl<'a, Buffer> IntoIterator for &'a mut Preallocated<Buffer>
+    where Buffer: BufferElement + AsMut<[Buffer::Element]>, Buffer::Element: 'a
+{
+    type Item = &'a mut Buffer::Element;
+    type IntoIter = SliceIterMut<'a, Buffer::Element>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_mut().iter_mut()
+    }
 }
\ No newline at end of file
@@ -0,0 +1,199 @@
+use crate::{
+    misc::BufferTooSmall, traits::CanAlloc,
+    std::{
+        ptr,
+        marker::PhantomData,
+        slice::{ self, Iter as SliceIter, IterMut as SliceIterMut },
+        mem::{ ManuallyDrop, MaybeUninit }
+    }
+};
+#[cfg(feature = "alloc")]
+use crate::std::boxed::Box;
+
+
+/// A buffer of possibly uninitialized elements
+///
+/// # Safety
+/// `PreallocatedUninit` relies on the buffer to be stable: an implementor must ensure that `as_uninit` and
+/// `as_uninit_mut` always return a slice over the same memory with the same length.
+pub unsafe trait UninitBuffer<T> {
+    /// The buffer as slice of possibly uninitialized elements
+    fn as_uninit(&self) -> &[MaybeUninit<T>];
+    /// The buffer as mutable slice of possibly uninitialized elements
+    fn as_uninit_mut(&mut self) -> &mut [MaybeUninit<T>];
+}
+unsafe impl<T, const N: usize> UninitBuffer<T> for [MaybeUninit<T>; N] {
+    fn as_uninit(&self) -> &[MaybeUninit<T>] {
+        self
+    }
+    fn as_uninit_mut(&mut self) -> &mut [MaybeUninit<T>] {
+        self
+    }
+}
+unsafe impl<T> UninitBuffer<T> for &mut [MaybeUninit<T>] {
+    fn as_uninit(&self) -> &[MaybeUninit<T>] {
+        self
+    }
+    fn as_uninit_mut(&mut self) -> &mut [MaybeUninit<T>] {
+        self
+    }
+}
+#[cfg(feature = "alloc")]
+unsafe impl<T> UninitBuffer<T> for Box<[MaybeUninit<T>]> {
+    fn as_uninit(&self) -> &[MaybeUninit<T>] {
+        self
+    }
+    fn as_uninit_mut(&mut self) -> &mut [MaybeUninit<T>] {
+        self
+    }
+}
+
+
+/// A wrapper that implements `CanAlloc` over a preallocated buffer of possibly uninitialized elements
+///
+/// __Discussion:__ In contrast to `Preallocated`, this wrapper does not require `T: Default` because vacated slots are
+/// left uninitialized. This also means that the buffer can be e.g. the spare capacity of a `Vec` (see
+/// `Vec::spare_capacity_mut`).
+pub struct PreallocatedUninit<T, Buffer> where Buffer: UninitBuffer<T> {
+    /// The wrapped buffer
+    buffer: Buffer,
+    /// The amount of initialized elements at the start of the buffer
+    used: usize,
+    /// The element type
+    _element: PhantomData<T>
+}
+impl<T, Buffer> PreallocatedUninit<T, Buffer> where Buffer: UninitBuffer<T> {
+    /// Create a new `PreallocatedUninit` instance by wrapping `buffer`
+    pub fn new(buffer: Buffer) -> Self {
+        Self { buffer, used: 0, _element: PhantomData }
+    }
+    /// Create a new `PreallocatedUninit` instance by wrapping `buffer` and sets the amount of used elements to `used`
+    ///
+    /// # Safety
+    /// The first `used` elements of `buffer` must be initialized, and `used` must not be greater than the buffer length
+    pub unsafe fn new_with_used(buffer: Buffer, used: usize) -> Self {
+        Self { buffer, used, _element: PhantomData }
+    }
+
+    /// The amount of elements `self` can hold
+    pub fn capacity(&self) -> usize {
+        self.buffer.as_uninit().len()
+    }
+
+    /// Drops the used elements and returns the wrapped buffer
+    pub fn into_inner(mut self) -> Buffer {
+        self.clear();
+        self.into_inner_with_used().0
+    }
+    /// Returns the wrapped buffer together with the amount of used elements without dropping them
+    ///
+    /// __Discussion:__ The first `used` elements of the buffer are initialized and the caller takes over their
+    /// ownership; e.g. if the buffer is the spare capacity of a `Vec`, the caller can use `Vec::set_len` to adopt them.
+    pub fn into_inner_with_used(self) -> (Buffer, usize) {
+        let this = ManuallyDrop::new(self);
+        // Safety: `this` is never used or dropped again, so the buffer is moved out exactly once
+        let buffer = unsafe { ptr::read(&this.buffer) };
+        (buffer, this.used)
+    }
+
+    /// Drops all used elements
+    fn clear(&mut self) {
+        let _ = self.truncate(0);
+    }
+}
+impl<T, Buffer> AsRef<[T]> for PreallocatedUninit<T, Buffer> where Buffer: UninitBuffer<T> {
+    fn as_ref(&self) -> &[T] {
+        let buffer = self.buffer.as_uninit();
+        // Safety: The first `used` elements are always initialized
+        unsafe { slice::from_raw_parts(buffer.as_ptr() as *const T, self.used) }
+    }
+}
+impl<T, Buffer> AsMut<[T]> for PreallocatedUninit<T, Buffer> where Buffer: UninitBuffer<T> {
+    fn as_mut(&mut self) -> &mut [T] {
+        let buffer = self.buffer.as_uninit_mut();
+        // Safety: The first `used` elements are always initialized
+        unsafe { slice::from_raw_parts_mut(buffer.as_mut_ptr() as *mut T, self.used) }
+    }
+}
+impl<T, Buffer> CanAlloc<T> for PreallocatedUninit<T, Buffer> where Buffer: UninitBuffer<T> {
+    type Error = BufferTooSmall;
+
+    fn try_push(&mut self, element: T) -> Result<(), (Self::Error, T)> {
+        // Ensure that the buffer is not full
+        let (used, available) = (self.used, self.capacity());
+        let slot = match self.buffer.as_uninit_mut().get_mut(used) {
+            Some(slot) => slot,
+            None => return Err((BufferTooSmall { needed: used.saturating_add(1), available }, element))
+        };
+
+        // Append the element
+        slot.write(element);
+        self.used += 1;
+        Ok(())
+    }
+
+    fn pop(&mut self) -> Result<Option<T>, Self::Error> {
+        // Validate that the used buffer is not empty
+        let last = match self.used.checked_sub(1) {
+            Some(last) => last,
+            None => return Ok(None)
+        };
+
+        // Move the last element out of the buffer and mark the slot as uninitialized
+        self.used = last;
+        // Safety: The slot was initialized and is not considered as initialized anymore
+        let element = unsafe { self.buffer.as_uninit()[last].assume_init_read() };
+        Ok(Some(element))
+    }
+
+    fn reserve(&mut self, additional: usize) -> Result<(), Self::Error> {
+        let (needed, available) = (self.used.saturating_add(additional), self.capacity());
+        match needed <= available {
+            true => Ok(()),
+            false => Err(BufferTooSmall { needed, available })
+        }
+    }
+    fn extend_from_slice(&mut self, elements: &[T]) -> Result<(), Self::Error> where T: Clone {
+        // Ensure that the remaining buffer is large enough
+        self.reserve(elements.len())?;
+
+        // Clone the elements one by one so that `used` stays consistent if `clone` panics
+        let slots = &mut self.buffer.as_uninit_mut()[self.used..];
+        for (slot, element) in slots.iter_mut().zip(elements) {
+            slot.write(element.clone());
+            self.used += 1;
+        }
+        Ok(())
+    }
+    fn truncate(&mut self, len: usize) -> Result<(), Self::Error> {
+        if len < self.used {
+            // Mark the elements as uninitialized first so that we leak instead of double-drop if a destructor panics
+            let truncated = &mut self.as_mut()[len..] as *mut [T];
+            self.used = len;
+            // Safety: The elements were initialized and are not considered as initialized anymore
+            unsafe { ptr::drop_in_place(truncated) };
+        }
+        Ok(())
+    }
+}
+impl<'a, T, Buffer> IntoIterator for &'a PreallocatedUninit<T, Buffer> where Buffer: UninitBuffer<T> {
+    type Item = &'a T;
+    type IntoIter = SliceIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_ref().iter()
+    }
+}
+impl<'a, T, Buffer> IntoIterator for &'a mut PreallocatedUninit<T, Buffer> where Buffer: UninitBuffer<T> {
+    type Item = &'a mut T;
+    type IntoIter = SliceIterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_mut().iter_mut()
+    }
+}
+impl<T, Buffer> Drop for PreallocatedUninit<T, Buffer> where Buffer: UninitBuffer<T> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
\ No newline at end of file
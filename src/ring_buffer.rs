@@ -0,0 +1,194 @@
+use crate::{
+    misc::BufferTooSmall, traits::{ AsSlices, CanAllocSegmented }, wrapper::Array,
+    std::{ mem, cmp }
+};
+
+
+/// The behavior of a `RingBuffer` if an element is pushed while the buffer is full
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FullPolicy {
+    /// Rejects the new element with `BufferTooSmall`
+    Reject,
+    /// Overwrites the oldest element
+    ///
+    ///  - Note: Bulk operations like `grow` still fail with `BufferTooSmall` if the free capacity is too small
+    ///  - Note: If an element is pushed to the front of a full buffer, the element at the back is overwritten instead
+    Overwrite
+}
+
+
+/// A wrapper that implements a bounded FIFO over a preallocated buffer
+///
+/// __Discussion:__ The elements are stored in a ring layout, so both front and back operations cost O(1). Wrapped into
+/// an `Array`, the elements are accessible via the `ArraySegmented*` traits, where all indices are relative to the
+/// logical front.
+pub struct RingBuffer<Buffer> {
+    /// The wrapped buffer
+    buffer: Buffer,
+    /// The index of the logical front within the buffer
+    head: usize,
+    /// The amount of elements used
+    len: usize,
+    /// The behavior if the buffer is full
+    policy: FullPolicy
+}
+impl<Buffer> RingBuffer<Buffer> {
+    /// Create a new `RingBuffer` instance by wrapping `buffer` with the given full-buffer `policy`
+    pub const fn new(buffer: Buffer, policy: FullPolicy) -> Self {
+        Self { buffer, head: 0, len: 0, policy }
+    }
+
+    /// The behavior if the buffer is full
+    pub const fn policy(&self) -> FullPolicy {
+        self.policy
+    }
+    /// Sets the behavior if the buffer is full
+    pub fn set_policy(&mut self, policy: FullPolicy) {
+        self.policy = policy;
+    }
+    /// The amount of elements `self` can hold
+    pub fn capacity<T>(&self) -> usize where Buffer: AsRef<[T]> {
+        self.buffer.as_ref().len()
+    }
+
+    /// Returns the wrapped buffer
+    ///
+    ///  - Note: The elements are stored in a ring layout and may thus wrap around the end of the buffer
+    #[inline(always)]
+    pub fn into_inner(self) -> Buffer {
+        self.buffer
+    }
+
+    /// Caps `head` and `len` to the buffer size because we cannot ensure that the buffer has not been resized somewhere
+    /// else
+    const fn capped(head: usize, len: usize, buffer_len: usize) -> (usize, usize) {
+        match buffer_len {
+            0 => (0, 0),
+            _ => (head % buffer_len, if len < buffer_len { len } else { buffer_len })
+        }
+    }
+    /// Caps the stored `head` and `len` to the buffer size and returns the buffer size
+    fn cap<T>(&mut self) -> usize where Buffer: AsRef<[T]> {
+        let buffer_len = self.buffer.as_ref().len();
+        (self.head, self.len) = Self::capped(self.head, self.len, buffer_len);
+        buffer_len
+    }
+    /// The buffer index behind the back element
+    ///
+    ///  - Note: This function does not overflow even if the buffer spans the whole address space, e.g. for ZSTs
+    const fn back_index(&self, buffer_len: usize) -> usize {
+        match self.len < buffer_len - self.head {
+            true => self.head + self.len,
+            false => self.len - (buffer_len - self.head)
+        }
+    }
+    /// Creates an error for the case that `additional` elements do not fit into the buffer
+    const fn too_small(&self, additional: usize, buffer_len: usize) -> BufferTooSmall {
+        BufferTooSmall { needed: self.len.saturating_add(additional), available: buffer_len }
+    }
+}
+impl<Buffer, T> AsSlices<T> for RingBuffer<Buffer> where Buffer: AsRef<[T]> + AsMut<[T]> {
+    fn as_slices(&self) -> (&[T], &[T]) {
+        let buffer = self.buffer.as_ref();
+        let (head, len) = Self::capped(self.head, self.len, buffer.len());
+
+        // Split the elements at the end of the buffer
+        let front_len = cmp::min(len, buffer.len() - head);
+        (&buffer[head .. head + front_len], &buffer[.. len - front_len])
+    }
+    fn as_slices_mut(&mut self) -> (&mut [T], &mut [T]) {
+        let buffer = self.buffer.as_mut();
+        let (head, len) = Self::capped(self.head, self.len, buffer.len());
+
+        // Split the elements at the end of the buffer
+        let front_len = cmp::min(len, buffer.len() - head);
+        let (back, front) = buffer.split_at_mut(head);
+        (&mut front[..front_len], &mut back[.. len - front_len])
+    }
+}
+impl<Buffer, T> CanAllocSegmented<T> for RingBuffer<Buffer> where Buffer: AsRef<[T]> + AsMut<[T]>, T: Default {
+    type Error = BufferTooSmall;
+
+    fn try_push_front(&mut self, element: T) -> Result<(), (Self::Error, T)> {
+        // Validate that the buffer can hold an element at all
+        let buffer_len = self.cap();
+        if buffer_len == 0 {
+            return Err((self.too_small(1, buffer_len), element));
+        }
+
+        // Move the head in front of the current head, which is the back element if the buffer is full
+        match (self.len < buffer_len, self.policy) {
+            (true, _) => self.len += 1,
+            (false, FullPolicy::Overwrite) => (),
+            (false, FullPolicy::Reject) => return Err((self.too_small(1, buffer_len), element))
+        }
+        self.head = self.head.checked_sub(1).unwrap_or(buffer_len - 1);
+        self.buffer.as_mut()[self.head] = element;
+        Ok(())
+    }
+    fn try_push_back(&mut self, element: T) -> Result<(), (Self::Error, T)> {
+        // Validate that the buffer can hold an element at all
+        let buffer_len = self.cap();
+        if buffer_len == 0 {
+            return Err((self.too_small(1, buffer_len), element));
+        }
+
+        // Append the element or overwrite the front element if the buffer is full
+        let index = self.back_index(buffer_len);
+        match (self.len < buffer_len, self.policy) {
+            (true, _) => self.len += 1,
+            (false, FullPolicy::Overwrite) => self.head = (self.head + 1) % buffer_len,
+            (false, FullPolicy::Reject) => return Err((self.too_small(1, buffer_len), element))
+        }
+        self.buffer.as_mut()[index] = element;
+        Ok(())
+    }
+    fn pop_front(&mut self) -> Result<Option<T>, Self::Error> {
+        // Validate that the buffer is not empty
+        let buffer_len = self.cap();
+        if self.len == 0 {
+            return Ok(None);
+        }
+
+        // Take the front element and replace it with a default element
+        let element = mem::take(&mut self.buffer.as_mut()[self.head]);
+        self.head = (self.head + 1) % buffer_len;
+        self.len -= 1;
+        Ok(Some(element))
+    }
+    fn pop_back(&mut self) -> Result<Option<T>, Self::Error> {
+        // Validate that the buffer is not empty
+        let buffer_len = self.cap();
+        if self.len == 0 {
+            return Ok(None);
+        }
+
+        // Take the back element and replace it with a default element
+        self.len -= 1;
+        let index = self.back_index(buffer_len);
+        let element = mem::take(&mut self.buffer.as_mut()[index]);
+        Ok(Some(element))
+    }
+
+    fn reserve(&mut self, additional: usize) -> Result<(), Self::Error> {
+        // Ensure that the free capacity is large enough; the overwrite policy only applies to single pushes so that
+        // e.g. `grow` cannot report success without reaching the requested length
+        let buffer_len = self.cap();
+        match buffer_len - self.len >= additional {
+            true => Ok(()),
+            false => Err(self.too_small(additional, buffer_len))
+        }
+    }
+}
+impl<Buffer> Array<RingBuffer<Buffer>> {
+    /// The amount of elements `self` can hold
+    pub fn capacity<T>(&self) -> usize where Buffer: AsRef<[T]> {
+        self.inner().capacity()
+    }
+    /// Whether `self` is full or not
+    pub fn is_full<T>(&self) -> bool where Buffer: AsRef<[T]> {
+        let inner = self.inner();
+        let buffer_len = inner.capacity();
+        RingBuffer::<Buffer>::capped(inner.head, inner.len, buffer_len).1 == buffer_len
+    }
+}
\ No newline at end of file
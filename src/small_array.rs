@@ -0,0 +1,182 @@
+use crate::{
+    misc::AllocError, traits::{ CanAlloc, CanAllocNew },
+    inline_array::InlineArray, tryvec::TryVec, wrapper::Array,
+    std::{
+        fmt::{ self, Debug, Formatter },
+        slice::{ Iter as SliceIter, IterMut as SliceIterMut }
+    }
+};
+
+
+/// The storage of a `SmallArray`
+enum Storage<T, const N: usize> {
+    /// The elements are stored inline
+    Inline(InlineArray<T, N>),
+    /// The elements have been spilled to the heap
+    Heap(TryVec<T>)
+}
+
+
+/// A wrapper that implements `CanAlloc` over an inline array with a capacity of `N` elements which spills to the heap
+/// if it outgrows the inline capacity
+pub struct SmallArray<T, const N: usize> {
+    /// The underlying storage
+    storage: Storage<T, N>
+}
+impl<T, const N: usize> SmallArray<T, N> {
+    /// Creates a new empty `SmallArray` instance
+    pub fn new() -> Self {
+        Self { storage: Storage::Inline(InlineArray::new()) }
+    }
+
+    /// Whether the elements are currently stored inline or not
+    pub fn is_inline(&self) -> bool {
+        matches!(self.storage, Storage::Inline(_))
+    }
+    /// The amount of elements `self` can hold without reallocating
+    pub fn capacity(&self) -> usize {
+        match &self.storage {
+            Storage::Inline(inline) => inline.capacity(),
+            Storage::Heap(heap) => heap.capacity()
+        }
+    }
+
+    /// Moves the inline elements to a newly allocated heap buffer with a capacity of at least `capacity` elements
+    fn spill(&mut self, capacity: usize) -> Result<(), AllocError> {
+        if let Storage::Inline(inline) = &mut self.storage {
+            // Allocate the heap buffer first so that `self` is not modified if the allocation fails
+            let mut heap = TryVec::with_capacity(capacity)?;
+            while let Some(element) = inline.pop().expect("Failed to pop from inline array?!") {
+                heap.push(element)?;
+            }
+
+            // Restore the order and replace the storage
+            heap.as_mut().reverse();
+            self.storage = Storage::Heap(heap);
+        }
+        Ok(())
+    }
+}
+impl<T, const N: usize> AsRef<[T]> for SmallArray<T, N> {
+    fn as_ref(&self) -> &[T] {
+        match &self.storage {
+            Storage::Inline(inline) => inline.as_ref(),
+            Storage::Heap(heap) => heap.as_ref()
+        }
+    }
+}
+impl<T, const N: usize> AsMut<[T]> for SmallArray<T, N> {
+    fn as_mut(&mut self) -> &mut [T] {
+        match &mut self.storage {
+            Storage::Inline(inline) => inline.as_mut(),
+            Storage::Heap(heap) => heap.as_mut()
+        }
+    }
+}
+impl<T, const N: usize> CanAlloc<T> for SmallArray<T, N> {
+    type Error = AllocError;
+
+    fn try_push(&mut self, element: T) -> Result<(), (Self::Error, T)> {
+        if let Err(e) = self.reserve(1) {
+            return Err((e, element));
+        }
+
+        match &mut self.storage {
+            Storage::Inline(inline) => inline.push(element).expect("Failed to push into reserved inline array?!"),
+            Storage::Heap(heap) => heap.try_push(element)?
+        }
+        Ok(())
+    }
+    fn pop(&mut self) -> Result<Option<T>, Self::Error> {
+        match &mut self.storage {
+            Storage::Inline(inline) => Ok(inline.pop().expect("Failed to pop from inline array?!")),
+            Storage::Heap(heap) => heap.pop()
+        }
+    }
+
+    fn reserve(&mut self, additional: usize) -> Result<(), Self::Error> {
+        match &mut self.storage {
+            Storage::Inline(inline) => match inline.reserve(additional) {
+                Ok(_) => Ok(()),
+                Err(_) => {
+                    // Spill to the heap if the inline capacity is too small
+                    let capacity = inline.as_ref().len().saturating_add(additional);
+                    self.spill(capacity)
+                }
+            },
+            Storage::Heap(heap) => heap.reserve(additional)
+        }
+    }
+    fn extend_from_slice(&mut self, elements: &[T]) -> Result<(), Self::Error> where T: Clone {
+        self.reserve(elements.len())?;
+        match &mut self.storage {
+            Storage::Inline(inline) => {
+                inline.extend_from_slice(elements).expect("Failed to extend reserved inline array?!")
+            },
+            Storage::Heap(heap) => heap.extend_from_slice(elements)?
+        }
+        Ok(())
+    }
+    fn truncate(&mut self, len: usize) -> Result<(), Self::Error> {
+        match &mut self.storage {
+            Storage::Inline(inline) => inline.truncate(len).expect("Failed to truncate inline array?!"),
+            Storage::Heap(heap) => heap.truncate(len)?
+        }
+        Ok(())
+    }
+}
+impl<T, const N: usize> CanAllocNew<T> for SmallArray<T, N> {
+    fn alloc_new() -> Result<Self, Self::Error> {
+        Ok(Self::new())
+    }
+}
+impl<T, const N: usize> Array<SmallArray<T, N>> {
+    /// Whether the elements are currently stored inline or not
+    pub fn is_inline(&self) -> bool {
+        self.inner().is_inline()
+    }
+}
+// - MARK: Propagate common trait implementations
+impl<T, const N: usize> Debug for SmallArray<T, N> where T: Debug {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+impl<T, const N: usize> Default for SmallArray<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<T, const N: usize> Clone for SmallArray<T, N> where T: Clone {
+    fn clone(&self) -> Self {
+        let storage = match &self.storage {
+            Storage::Inline(inline) => Storage::Inline(inline.clone()),
+            Storage::Heap(heap) => Storage::Heap(heap.clone())
+        };
+        Self { storage }
+    }
+}
+impl<T, const N: usize> PartialEq for SmallArray<T, N> where T: PartialEq {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_ref().eq(other.as_ref())
+    }
+}
+impl<T, const N: usize> Eq for SmallArray<T, N> where T: Eq {
+    /* Eq is a marker trait; no implementation required */
+}
+impl<'a, T, const N: usize> IntoIterator for &'a SmallArray<T, N> {
+    type Item = &'a T;
+    type IntoIter = SliceIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_ref().iter()
+    }
+}
+impl<'a, T, const N: usize> IntoIterator for &'a mut SmallArray<T, N> {
+    type Item = &'a mut T;
+    type IntoIter = SliceIterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_mut().iter_mut()
+    }
+}
\ No newline at end of file
@@ -1,12 +1,22 @@
 use crate::{
-    misc::BufferTooSmall, wrapper::Array,
+    misc::{ self, ArrayError, BufferTooSmall, LengthMismatch }, wrapper::Array,
+    iter::{ Chunks, ChunksExact, RChunks, Windows, ChunksMut, ChunksExactMut, Drain },
     std::{
-        fmt::Debug, ops::RangeBounds,
+        fmt::Debug, iter::Chain, ops::{ Range, RangeBounds },
         slice::{ Iter as SliceIter, IterMut as SliceIterMut }
     }
 };
-#[cfg(feature = "std")]
-use crate::misc::WillPanic;
+#[cfg(feature = "alloc")]
+use crate::{
+    misc::WillPanic,
+    std::{ vec::Vec, collections::VecDeque }
+};
+
+
+/// Two adjacent subranges of an array
+pub type ArraySplit<'a, T> = (Array<&'a [T]>, Array<&'a [T]>);
+/// Two adjacent mutable subranges of an array
+pub type ArraySplitMut<'a, T> = (Array<&'a mut [T]>, Array<&'a mut [T]>);
 
 
 /// A trait for referencable linear array types
@@ -28,11 +38,82 @@ pub trait ArrayRef<T> {
     /// Returns a reference to the last element
     fn last(&self) -> Option<&T>;
 
+    /// Splits `self` into the elements before `mid` and the elements starting at `mid`
+    fn split_at(&self, mid: usize) -> Option<ArraySplit<'_, T>>;
+    /// Splits `self` into the first element and the remaining elements
+    fn split_first(&self) -> Option<(&T, Array<&[T]>)>;
+    /// Splits `self` into the first `len` elements and the remaining elements
+    fn split_first_n(&self, len: usize) -> Option<ArraySplit<'_, T>>;
+    /// Splits `self` into the last element and the remaining elements
+    fn split_last(&self) -> Option<(&T, Array<&[T]>)>;
+    /// Splits `self` into the last `len` elements and the remaining elements
+    fn split_last_n(&self, len: usize) -> Option<ArraySplit<'_, T>>;
+
     /// Returns an iterator that references the elements
-    fn iter(&self) -> SliceIter<T>;
+    fn iter(&self) -> SliceIter<'_, T>;
+    /// Returns an iterator over subranges of `size` elements starting at the front, or `None` if `size` is zero
+    ///
+    ///  - Note: The last subrange may be shorter than `size`
+    fn chunks(&self, size: usize) -> Option<Chunks<'_, T>>;
+    /// Returns an iterator over subranges of exactly `size` elements starting at the front, or `None` if `size` is zero
+    fn chunks_exact(&self, size: usize) -> Option<ChunksExact<'_, T>>;
+    /// Returns an iterator over subranges of `size` elements starting at the back, or `None` if `size` is zero
+    ///
+    ///  - Note: The last subrange may be shorter than `size`
+    fn rchunks(&self, size: usize) -> Option<RChunks<'_, T>>;
+    /// Returns an iterator over all overlapping subranges of `size` elements, or `None` if `size` is zero
+    fn windows(&self, size: usize) -> Option<Windows<'_, T>>;
 
-    /// Clones `self` to `target`
+    /// Clones `self` to the start of `target`
+    ///
+    ///  - Note: If `target` is longer than `self`, the remaining elements of `target` are left untouched
     fn clone_to<Target>(&self, target: &mut Target) -> Result<(), BufferTooSmall> where Target: ArrayMut<T>, T: Clone;
+    /// Clones `self` to `target` if both have the same length
+    fn clone_to_exact<Target>(&self, target: &mut Target) -> Result<(), LengthMismatch>
+        where Target: ArrayMut<T>, T: Clone;
+    /// Copies `self` to `target` if both have the same length
+    fn copy_to_exact<Target>(&self, target: &mut Target) -> Result<(), LengthMismatch>
+        where Target: ArrayMut<T>, T: Copy;
+    /// Clones as many elements of `self` as possible to the start of `target` and returns the amount of cloned elements
+    fn clone_to_prefix<Target>(&self, target: &mut Target) -> usize where Target: ArrayMut<T>, T: Clone;
+    /// Copies as many elements of `self` as possible to the start of `target` and returns the amount of copied elements
+    fn copy_to_prefix<Target>(&self, target: &mut Target) -> usize where Target: ArrayMut<T>, T: Copy;
+
+    /// Gets an element or returns a descriptive error
+    fn try_get(&self, index: usize) -> Result<&T, ArrayError> {
+        let len = self.len();
+        self.get(index).ok_or(ArrayError::OutOfBounds { index, len })
+    }
+    /// Gets a subrange or returns a descriptive error
+    fn try_get_n<Range>(&self, range: Range) -> Result<Array<&[T]>, ArrayError> where Range: RangeBounds<usize> {
+        let len = self.len();
+        let (start, end) = misc::resolve_range(range, len).map(|r| (r.start, r.end))?;
+        self.get_n(start..end).ok_or(ArrayError::InvalidRange { start, end, len })
+    }
+    /// Splits `self` at `mid` or returns a descriptive error
+    fn try_split_at(&self, mid: usize) -> Result<ArraySplit<'_, T>, ArrayError> {
+        let len = self.len();
+        self.split_at(mid).ok_or(ArrayError::OutOfBounds { index: mid, len })
+    }
+    /// Clones `self` to the start of `target` or returns a descriptive error
+    fn try_clone_to<Target>(&self, target: &mut Target) -> Result<(), ArrayError> where Target: ArrayMut<T>, T: Clone {
+        self.clone_to(target)
+            .map_err(|BufferTooSmall { needed, available }| ArrayError::BufferTooSmall { needed, available })
+    }
+    /// Clones `self` to `target` if both have the same length or returns a descriptive error
+    fn try_clone_to_exact<Target>(&self, target: &mut Target) -> Result<(), ArrayError>
+        where Target: ArrayMut<T>, T: Clone
+    {
+        let (expected, found) = (self.len(), target.len());
+        self.clone_to_exact(target).map_err(|_| ArrayError::LengthMismatch { expected, found })
+    }
+    /// Copies `self` to `target` if both have the same length or returns a descriptive error
+    fn try_copy_to_exact<Target>(&self, target: &mut Target) -> Result<(), ArrayError>
+        where Target: ArrayMut<T>, T: Copy
+    {
+        let (expected, found) = (self.len(), target.len());
+        self.copy_to_exact(target).map_err(|_| ArrayError::LengthMismatch { expected, found })
+    }
 }
 
 
@@ -51,8 +132,71 @@ pub trait ArrayMut<T>: ArrayRef<T> {
     /// Returns a mutable reference to the last element
     fn last_mut(&mut self) -> Option<&mut T>;
 
+    /// Splits `self` into the mutable elements before `mid` and the mutable elements starting at `mid`
+    fn split_at_mut(&mut self, mid: usize) -> Option<ArraySplitMut<'_, T>>;
+    /// Splits `self` into the mutable first element and the remaining mutable elements
+    fn split_first_mut(&mut self) -> Option<(&mut T, Array<&mut [T]>)>;
+    /// Splits `self` into the first `len` mutable elements and the remaining mutable elements
+    fn split_first_n_mut(&mut self, len: usize) -> Option<ArraySplitMut<'_, T>>;
+    /// Splits `self` into the mutable last element and the remaining mutable elements
+    fn split_last_mut(&mut self) -> Option<(&mut T, Array<&mut [T]>)>;
+    /// Splits `self` into the last `len` mutable elements and the remaining mutable elements
+    fn split_last_n_mut(&mut self, len: usize) -> Option<ArraySplitMut<'_, T>>;
+
     /// Returns an iterator that mutably references the elements
-    fn iter_mut(&mut self) -> SliceIterMut<T>;
+    fn iter_mut(&mut self) -> SliceIterMut<'_, T>;
+    /// Returns an iterator over mutable subranges of `size` elements starting at the front, or `None` if `size` is zero
+    ///
+    ///  - Note: The last subrange may be shorter than `size`
+    fn chunks_mut(&mut self, size: usize) -> Option<ChunksMut<'_, T>>;
+    /// Returns an iterator over mutable subranges of exactly `size` elements starting at the front, or `None` if `size`
+    /// is zero
+    fn chunks_exact_mut(&mut self, size: usize) -> Option<ChunksExactMut<'_, T>>;
+
+    /// Clones `source` to `self` if both have the same length
+    fn clone_from_exact<Source>(&mut self, source: &Source) -> Result<(), LengthMismatch>
+        where Source: ArrayRef<T>, T: Clone;
+    /// Copies `source` to `self` if both have the same length
+    fn copy_from_exact<Source>(&mut self, source: &Source) -> Result<(), LengthMismatch>
+        where Source: ArrayRef<T>, T: Copy;
+    /// Clones as many elements of `source` as possible to the start of `self` and returns the amount of cloned elements
+    fn clone_from_prefix<Source>(&mut self, source: &Source) -> usize where Source: ArrayRef<T>, T: Clone;
+    /// Copies as many elements of `source` as possible to the start of `self` and returns the amount of copied elements
+    fn copy_from_prefix<Source>(&mut self, source: &Source) -> usize where Source: ArrayRef<T>, T: Copy;
+
+    /// Swaps the elements at `a` and `b`
+    fn swap(&mut self, a: usize, b: usize) -> Option<()>;
+    /// Swaps the elements within `a` with the elements within `b` if both ranges have the same length and don't overlap
+    fn swap_ranges<RangeA, RangeB>(&mut self, a: RangeA, b: RangeB) -> Option<()>
+        where RangeA: RangeBounds<usize>, RangeB: RangeBounds<usize>;
+    /// Replaces the element at `index` with `element` and returns the previous element, or returns `element` as error
+    /// if `index` is out of bounds
+    fn replace(&mut self, index: usize, element: T) -> Result<T, T>;
+    /// Takes the element at `index` and replaces it with `Default`
+    fn take(&mut self, index: usize) -> Option<T> where T: Default;
+
+    /// Copies the elements within `source` to `dest` within `self`, or returns `None` without modifying `self` if
+    /// `source` is invalid or there is not enough space at `dest`
+    ///
+    ///  - Note: The source and destination ranges may overlap
+    fn copy_within<Range>(&mut self, source: Range, dest: usize) -> Option<()>
+        where Range: RangeBounds<usize>, T: Copy;
+    /// Clones the elements within `source` to `dest` within `self`, or returns `None` without modifying `self` if
+    /// `source` is invalid or there is not enough space at `dest`
+    ///
+    ///  - Note: The source and destination ranges may overlap
+    fn clone_within<Range>(&mut self, source: Range, dest: usize) -> Option<()>
+        where Range: RangeBounds<usize>, T: Clone;
+
+    /// Fills `self` with clones of `value`
+    ///
+    ///  - Note: This lowers to a `memset` for `Copy` types where possible
+    fn fill(&mut self, value: T) where T: Clone;
+    /// Fills `self` with elements returned by `init`
+    fn fill_with(&mut self, init: impl FnMut() -> T);
+    /// Fills the elements within `range` with clones of `value`, or returns `None` without modifying `self` if `range`
+    /// is invalid
+    fn fill_range<Range>(&mut self, range: Range, value: T) -> Option<()> where Range: RangeBounds<usize>, T: Clone;
 
     /// Rotates the elements left by `count` fields
     fn rotate_left(&mut self, count: usize);
@@ -60,19 +204,47 @@ pub trait ArrayMut<T>: ArrayRef<T> {
     fn rotate_right(&mut self, count: usize);
     /// Reverses the order of elements in the slice
     fn reverse(&mut self);
+
+    /// Gets a mutable reference to an element or returns a descriptive error
+    fn try_get_mut(&mut self, index: usize) -> Result<&mut T, ArrayError> {
+        let len = self.len();
+        self.get_mut(index).ok_or(ArrayError::OutOfBounds { index, len })
+    }
+    /// Gets a mutable subrange or returns a descriptive error
+    fn try_get_n_mut<Range>(&mut self, range: Range) -> Result<Array<&mut [T]>, ArrayError>
+        where Range: RangeBounds<usize>
+    {
+        let len = self.len();
+        let (start, end) = misc::resolve_range(range, len).map(|r| (r.start, r.end))?;
+        self.get_n_mut(start..end).ok_or(ArrayError::InvalidRange { start, end, len })
+    }
+    /// Splits `self` mutably at `mid` or returns a descriptive error
+    fn try_split_at_mut(&mut self, mid: usize) -> Result<ArraySplitMut<'_, T>, ArrayError> {
+        let len = self.len();
+        self.split_at_mut(mid).ok_or(ArrayError::OutOfBounds { index: mid, len })
+    }
+    /// Clones `source` to `self` if both have the same length or returns a descriptive error
+    fn try_clone_from_exact<Source>(&mut self, source: &Source) -> Result<(), ArrayError>
+        where Source: ArrayRef<T>, T: Clone
+    {
+        let (expected, found) = (self.len(), source.len());
+        self.clone_from_exact(source).map_err(|_| ArrayError::LengthMismatch { expected, found })
+    }
+    /// Copies `source` to `self` if both have the same length or returns a descriptive error
+    fn try_copy_from_exact<Source>(&mut self, source: &Source) -> Result<(), ArrayError>
+        where Source: ArrayRef<T>, T: Copy
+    {
+        let (expected, found) = (self.len(), source.len());
+        self.copy_from_exact(source).map_err(|_| ArrayError::LengthMismatch { expected, found })
+    }
 }
 
 
-/// A trait for allocatable/resizeable linear array types
+/// A trait for allocatable/resizeable linear array types that can grow and shrink in place
 pub trait ArrayAlloc<T>: ArrayMut<T> + Sized {
     /// An alloc related error
     type Error: Debug;
     
-    /// Creates a newly allocated instance of `Self`
-    fn alloc_new() -> Result<Self, Self::Error>;
-    /// Clones `source` into a newly allocated instance of `Self`
-    fn alloc_clone<Source>(source: &Source) -> Result<Self, Self::Error> where Source: ArrayRef<T>, T: Clone;
-
     /// Grows `self` to the given capacity if the current length is smaller than `len` and inits new elements using `init`
     fn grow_with(&mut self, len: usize, init: impl FnMut() -> T) -> Result<(), Self::Error>;
     /// Grows `self` to the given capacity if the current length is smaller than `len` and inits new elements using
@@ -80,6 +252,14 @@ pub trait ArrayAlloc<T>: ArrayMut<T> + Sized {
     fn grow(&mut self, len: usize) -> Result<(), Self::Error> where T: Default;
     /// Shrinks `self` to the given capacity if the current length is larger than `len`
     fn shrink(&mut self, len: usize) -> Result<(), Self::Error>;
+    /// Grows `self` like `grow`, but fails if the current length is larger than `len`
+    fn grow_exact(&mut self, len: usize) -> Result<(), ArrayError<Self::Error>> where T: Default;
+    /// Shrinks `self` like `shrink`, but fails if the current length is smaller than `len`
+    fn shrink_exact(&mut self, len: usize) -> Result<(), ArrayError<Self::Error>>;
+    /// Grows or shrinks `self` to `len` elements and inits new elements with clones of `value`
+    fn resize(&mut self, len: usize, value: T) -> Result<(), Self::Error> where T: Clone;
+    /// Grows or shrinks `self` to `len` elements and inits new elements using `init`
+    fn resize_with(&mut self, len: usize, init: impl FnMut() -> T) -> Result<(), Self::Error>;
 
     /// Pushes an `element` to the front of `self`
     fn push_front(&mut self, element: T) -> Result<(), Self::Error>;
@@ -90,15 +270,100 @@ pub trait ArrayAlloc<T>: ArrayMut<T> + Sized {
     fn push_back(&mut self, element: T) -> Result<(), Self::Error>;
     /// Pushes some `elements` to the front of `self`
     fn push_n_back<Source>(&mut self, elements: &Source) -> Result<(), Self::Error> where Source: ArrayRef<T>, T: Clone;
+    /// Clones the elements within `range` to the back of `self`, or returns `None` if `range` is invalid
+    fn extend_from_within<Range>(&mut self, range: Range) -> Result<Option<()>, Self::Error>
+        where Range: RangeBounds<usize>, T: Clone;
+    /// Moves the `elements` to the back of `self` until an element is rejected
+    ///
+    ///  - Note: If an element is rejected, the previous elements remain in `self` and the error is returned together
+    ///    with the rejected element
+    fn try_extend<Elements>(&mut self, elements: Elements) -> Result<(), (Self::Error, T)>
+        where Elements: IntoIterator<Item = T>;
+    /// Moves either all `elements` or no elements at all to the back of `self`
+    ///
+    ///  - Note: The capacity is checked upfront using the lower bound of the iterator's `size_hint`; if an element is
+    ///    rejected nevertheless, all previously pushed elements are removed again
+    fn try_extend_all<Elements>(&mut self, elements: Elements) -> Result<(), Self::Error>
+        where Elements: IntoIterator<Item = T>;
 
     /// Pops an `element` from the front of `self`
     fn pop_front(&mut self) -> Result<Option<T>, Self::Error>;
-    /// Pops multiple `elements` from the front of `self`
-    fn pop_n_front(&mut self, len: usize) -> Result<Option<Self>, Self::Error>;
     /// Pops an `element` from the back of `self`
     fn pop_back(&mut self) -> Result<Option<T>, Self::Error>;
+
+    /// Inserts an `element` at `index` and shifts all following elements to the back, or returns `None` if `index` is
+    /// out of bounds
+    fn insert(&mut self, index: usize, element: T) -> Result<Option<()>, Self::Error>;
+    /// Inserts some `elements` at `index` and shifts all following elements to the back, or returns `None` if `index`
+    /// is out of bounds
+    fn insert_n<Source>(&mut self, index: usize, elements: &Source) -> Result<Option<()>, Self::Error>
+        where Source: ArrayRef<T>, T: Clone;
+    /// Removes the element at `index` and shifts all following elements to the front, or returns `None` if `index` is
+    /// out of bounds
+    fn remove(&mut self, index: usize) -> Result<Option<T>, Self::Error>;
+    /// Removes the element at `index` and replaces it with the last element, or returns `None` if `index` is out of
+    /// bounds
+    fn swap_remove(&mut self, index: usize) -> Result<Option<T>, Self::Error>;
+    /// Removes the elements within `range` and returns an iterator over the removed elements, or returns `None` if
+    /// `range` is invalid
+    fn drain<Range>(&mut self, range: Range) -> Option<Drain<'_, T, Self>> where Range: RangeBounds<usize>;
+
+    /// Retains only the elements for which `keep` returns `true` and preserves their order
+    ///
+    ///  - Note: If `keep` panics, `self` still contains all elements, but in an unspecified order
+    fn retain(&mut self, keep: impl FnMut(&T) -> bool) -> Result<(), Self::Error>;
+    /// Retains only the elements for which `keep` returns `true` and preserves their order
+    ///
+    ///  - Note: If `keep` panics, `self` still contains all elements, but in an unspecified order
+    fn retain_mut(&mut self, keep: impl FnMut(&mut T) -> bool) -> Result<(), Self::Error>;
+    /// Removes consecutive duplicate elements
+    fn dedup(&mut self) -> Result<(), Self::Error> where T: PartialEq;
+    /// Removes consecutive elements for which `same_bucket(element, previous)` returns `true`
+    ///
+    ///  - Note: If `same_bucket` panics, `self` still contains all elements, but in an unspecified order
+    fn dedup_by(&mut self, same_bucket: impl FnMut(&mut T, &mut T) -> bool) -> Result<(), Self::Error>;
+    /// Removes consecutive elements which map to the same key
+    ///
+    ///  - Note: If `key` panics, `self` still contains all elements, but in an unspecified order
+    fn dedup_by_key<Key>(&mut self, key: impl FnMut(&mut T) -> Key) -> Result<(), Self::Error> where Key: PartialEq;
+
+    /// Replaces the elements within `range` with clones of `elements`, or returns `None` if `range` is invalid
+    ///
+    ///  - Note: If the operation fails, `self` is left unmodified
+    fn replace_range<Range, Source>(&mut self, range: Range, elements: &Source) -> Result<Option<()>, Self::Error>
+        where Range: RangeBounds<usize>, Source: ArrayRef<T>, T: Clone;
+
+    /// Moves all elements of `other` to the end of `self`, leaving `other` empty
+    ///
+    ///  - Note: The required capacity is reserved upfront, so no element is moved if the allocation fails
+    fn append(&mut self, other: &mut Self) -> Result<(), Self::Error>;
+}
+
+
+/// A trait for allocatable/resizeable linear array types that can create new instances
+pub trait ArrayAllocNew<T>: ArrayAlloc<T> {
+    /// Creates a newly allocated instance of `Self`
+    fn alloc_new() -> Result<Self, Self::Error>;
+    /// Clones `source` into a newly allocated instance of `Self`
+    fn alloc_clone<Source>(source: &Source) -> Result<Self, Self::Error> where Source: ArrayRef<T>, T: Clone;
+    /// Collects `elements` into a newly allocated instance of `Self`
+    fn alloc_from_iter<Elements>(elements: Elements) -> Result<Self, Self::Error>
+        where Elements: IntoIterator<Item = T>;
+
+    /// Pops multiple `elements` from the front of `self`
+    fn pop_n_front(&mut self, len: usize) -> Result<Option<Self>, Self::Error>;
     /// Pops multiple `elements` from the back of `self`
     fn pop_n_back(&mut self, len: usize) -> Result<Option<Self>, Self::Error>;
+
+    /// Removes the elements within `range` and shifts all following elements to the front, or returns `None` if `range`
+    /// is invalid
+    fn remove_n<Range>(&mut self, range: Range) -> Result<Option<Self>, Self::Error> where Range: RangeBounds<usize>;
+
+    /// Moves the elements starting at `at` into a newly allocated instance of `Self`, or returns `None` if `at` is out
+    /// of bounds
+    ///
+    ///  - Note: In contrast to `pop_n_back`, this function takes the split position instead of the amount of elements
+    fn split_off(&mut self, at: usize) -> Result<Option<Self>, Self::Error>;
 }
 
 
@@ -107,11 +372,6 @@ pub trait ArrayAlloc<T>: ArrayMut<T> + Sized {
 ///  - Note: This trait adopts Rust's "panic on allocation failure" policy. While this trait reintroduces a panic cause,
 ///    it's usually much more convenient to use – especially for `std`-types which use `WillPanic` anyway.
 pub trait ArrayAllocPanic<T>: ArrayMut<T> + Sized {
-    /// Creates a newly allocated instance of `Self`
-    fn alloc_new() -> Self;
-    /// Clones `source` into a newly allocated instance of `Self`
-    fn alloc_clone<Source>(source: &Source) -> Self where Source: ArrayRef<T>, T: Clone;
-
     /// Grows `self` to the given capacity if the current length is smaller than `len` and inits new elements using `init`
     fn grow_with(&mut self, len: usize, init: impl FnMut() -> T);
     /// Grows `self` to the given capacity if the current length is smaller than `len` and inits new elements using
@@ -119,6 +379,14 @@ pub trait ArrayAllocPanic<T>: ArrayMut<T> + Sized {
     fn grow(&mut self, len: usize) where T: Default;
     /// Shrinks `self` to the given capacity if the current length is larger than `len`
     fn shrink(&mut self, len: usize);
+    /// Grows `self` like `grow`, but fails if the current length is larger than `len`
+    fn grow_exact(&mut self, len: usize) -> Result<(), ArrayError> where T: Default;
+    /// Shrinks `self` like `shrink`, but fails if the current length is smaller than `len`
+    fn shrink_exact(&mut self, len: usize) -> Result<(), ArrayError>;
+    /// Grows or shrinks `self` to `len` elements and inits new elements with clones of `value`
+    fn resize(&mut self, len: usize, value: T) where T: Clone;
+    /// Grows or shrinks `self` to `len` elements and inits new elements using `init`
+    fn resize_with(&mut self, len: usize, init: impl FnMut() -> T);
 
     /// Pushes an `element` to the front of `self`
     fn push_front(&mut self, element: T);
@@ -128,24 +396,54 @@ pub trait ArrayAllocPanic<T>: ArrayMut<T> + Sized {
     fn push_back(&mut self, element: T);
     /// Pushes some `elements` to the front of `self`
     fn push_n_back<Source>(&mut self, elements: &Source) where Source: ArrayRef<T>, T: Clone;
+    /// Clones the elements within `range` to the back of `self`, or returns `None` if `range` is invalid
+    fn extend_from_within<Range>(&mut self, range: Range) -> Option<()> where Range: RangeBounds<usize>, T: Clone;
 
     /// Pops an `element` from the front of `self`
     fn pop_front(&mut self) -> Option<T>;
-    /// Pops multiple `elements` from the front of `self`
-    fn pop_n_front(&mut self, len: usize) -> Option<Self>;
     /// Pops an `element` from the back of `self`
     fn pop_back(&mut self) -> Option<T>;
-    /// Pops multiple `elements` from the back of `self`
-    fn pop_n_back(&mut self, len: usize) -> Option<Self>;
+
+    /// Inserts an `element` at `index` and shifts all following elements to the back, or returns `None` if `index` is
+    /// out of bounds
+    fn insert(&mut self, index: usize, element: T) -> Option<()>;
+    /// Inserts some `elements` at `index` and shifts all following elements to the back, or returns `None` if `index`
+    /// is out of bounds
+    fn insert_n<Source>(&mut self, index: usize, elements: &Source) -> Option<()> where Source: ArrayRef<T>, T: Clone;
+    /// Removes the element at `index` and shifts all following elements to the front, or returns `None` if `index` is
+    /// out of bounds
+    fn remove(&mut self, index: usize) -> Option<T>;
+    /// Removes the element at `index` and replaces it with the last element, or returns `None` if `index` is out of
+    /// bounds
+    fn swap_remove(&mut self, index: usize) -> Option<T>;
+
+    /// Retains only the elements for which `keep` returns `true` and preserves their order
+    ///
+    ///  - Note: If `keep` panics, `self` still contains all elements, but in an unspecified order
+    fn retain(&mut self, keep: impl FnMut(&T) -> bool);
+    /// Retains only the elements for which `keep` returns `true` and preserves their order
+    ///
+    ///  - Note: If `keep` panics, `self` still contains all elements, but in an unspecified order
+    fn retain_mut(&mut self, keep: impl FnMut(&mut T) -> bool);
+    /// Removes consecutive duplicate elements
+    fn dedup(&mut self) where T: PartialEq;
+    /// Removes consecutive elements for which `same_bucket(element, previous)` returns `true`
+    ///
+    ///  - Note: If `same_bucket` panics, `self` still contains all elements, but in an unspecified order
+    fn dedup_by(&mut self, same_bucket: impl FnMut(&mut T, &mut T) -> bool);
+    /// Removes consecutive elements which map to the same key
+    ///
+    ///  - Note: If `key` panics, `self` still contains all elements, but in an unspecified order
+    fn dedup_by_key<Key>(&mut self, key: impl FnMut(&mut T) -> Key) where Key: PartialEq;
+
+    /// Replaces the elements within `range` with clones of `elements`, or returns `None` if `range` is invalid
+    fn replace_range<Range, Source>(&mut self, range: Range, elements: &Source) -> Option<()>
+        where Range: RangeBounds<usize>, Source: ArrayRef<T>, T: Clone;
+
+    /// Moves all elements of `other` to the end of `self`, leaving `other` empty
+    fn append(&mut self, other: &mut Self);
 }
 impl<T, Array> ArrayAllocPanic<T> for Array where Array: ArrayAlloc<T> {
-    fn alloc_new() -> Self {
-        <Self as ArrayAlloc<T>>::alloc_new().expect("Allocation error")
-    }
-    fn alloc_clone<Source>(elements: &Source) -> Self where Source: ArrayRef<T>, T: Clone {
-        <Self as ArrayAlloc<T>>::alloc_clone(elements).expect("Allocation error")
-    }
-
     fn grow_with(&mut self, len: usize, init: impl FnMut() -> T) {
         <Self as ArrayAlloc<T>>::grow_with(self, len, init).expect("Allocation error")
     }
@@ -155,6 +453,20 @@ impl<T, Array> ArrayAllocPanic<T> for Array where Array: ArrayAlloc<T> {
     fn shrink(&mut self, len: usize) {
         <Self as ArrayAlloc<T>>::shrink(self, len).expect("Allocation error")
     }
+    fn grow_exact(&mut self, len: usize) -> Result<(), ArrayError> where T: Default {
+        let result = <Self as ArrayAlloc<T>>::grow_exact(self, len);
+        result.map_err(|e| e.map_alloc(|e| panic!("Allocation error: {:?}", e)))
+    }
+    fn shrink_exact(&mut self, len: usize) -> Result<(), ArrayError> {
+        let result = <Self as ArrayAlloc<T>>::shrink_exact(self, len);
+        result.map_err(|e| e.map_alloc(|e| panic!("Allocation error: {:?}", e)))
+    }
+    fn resize(&mut self, len: usize, value: T) where T: Clone {
+        <Self as ArrayAlloc<T>>::resize(self, len, value).expect("Allocation error")
+    }
+    fn resize_with(&mut self, len: usize, init: impl FnMut() -> T) {
+        <Self as ArrayAlloc<T>>::resize_with(self, len, init).expect("Allocation error")
+    }
 
     fn push_front(&mut self, element: T) {
         <Self as ArrayAlloc<T>>::push_front(self, element).expect("Allocation error")
@@ -168,47 +480,497 @@ impl<T, Array> ArrayAllocPanic<T> for Array where Array: ArrayAlloc<T> {
     fn push_n_back<Source>(&mut self, elements: &Source) where Source: ArrayRef<T>, T: Clone {
         <Self as ArrayAlloc<T>>::push_n_back(self, elements).expect("Allocation error")
     }
+    fn extend_from_within<Range>(&mut self, range: Range) -> Option<()> where Range: RangeBounds<usize>, T: Clone {
+        <Self as ArrayAlloc<T>>::extend_from_within(self, range).expect("Allocation error")
+    }
 
     fn pop_front(&mut self) -> Option<T> {
         <Self as ArrayAlloc<T>>::pop_front(self).expect("Allocation error")
     }
-    fn pop_n_front(&mut self, len: usize) -> Option<Self> {
-        <Self as ArrayAlloc<T>>::pop_n_front(self, len).expect("Allocation error")
-    }
     fn pop_back(&mut self) -> Option<T> {
         <Self as ArrayAlloc<T>>::pop_back(self).expect("Allocation error")
     }
+
+    fn insert(&mut self, index: usize, element: T) -> Option<()> {
+        <Self as ArrayAlloc<T>>::insert(self, index, element).expect("Allocation error")
+    }
+    fn insert_n<Source>(&mut self, index: usize, elements: &Source) -> Option<()> where Source: ArrayRef<T>, T: Clone {
+        <Self as ArrayAlloc<T>>::insert_n(self, index, elements).expect("Allocation error")
+    }
+    fn remove(&mut self, index: usize) -> Option<T> {
+        <Self as ArrayAlloc<T>>::remove(self, index).expect("Allocation error")
+    }
+    fn swap_remove(&mut self, index: usize) -> Option<T> {
+        <Self as ArrayAlloc<T>>::swap_remove(self, index).expect("Allocation error")
+    }
+
+    fn retain(&mut self, keep: impl FnMut(&T) -> bool) {
+        <Self as ArrayAlloc<T>>::retain(self, keep).expect("Allocation error")
+    }
+    fn retain_mut(&mut self, keep: impl FnMut(&mut T) -> bool) {
+        <Self as ArrayAlloc<T>>::retain_mut(self, keep).expect("Allocation error")
+    }
+    fn dedup(&mut self) where T: PartialEq {
+        <Self as ArrayAlloc<T>>::dedup(self).expect("Allocation error")
+    }
+    fn dedup_by(&mut self, same_bucket: impl FnMut(&mut T, &mut T) -> bool) {
+        <Self as ArrayAlloc<T>>::dedup_by(self, same_bucket).expect("Allocation error")
+    }
+    fn dedup_by_key<Key>(&mut self, key: impl FnMut(&mut T) -> Key) where Key: PartialEq {
+        <Self as ArrayAlloc<T>>::dedup_by_key(self, key).expect("Allocation error")
+    }
+
+    fn replace_range<Range, Source>(&mut self, range: Range, elements: &Source) -> Option<()>
+        where Range: RangeBounds<usize>, Source: ArrayRef<T>, T: Clone
+    {
+        <Self as ArrayAlloc<T>>::replace_range(self, range, elements).expect("Allocation error")
+    }
+
+    fn append(&mut self, other: &mut Self) {
+        <Self as ArrayAlloc<T>>::append(self, other).expect("Allocation error")
+    }
+}
+
+
+/// An infallible/panicking variant of `ArrayAllocNew`
+///
+///  - Note: See `ArrayAllocPanic` for a discussion of the panic policy
+pub trait ArrayAllocNewPanic<T>: ArrayAllocPanic<T> {
+    /// Creates a newly allocated instance of `Self`
+    fn alloc_new() -> Self;
+    /// Clones `source` into a newly allocated instance of `Self`
+    fn alloc_clone<Source>(source: &Source) -> Self where Source: ArrayRef<T>, T: Clone;
+    /// Collects `elements` into a newly allocated instance of `Self`
+    fn alloc_from_iter<Elements>(elements: Elements) -> Self where Elements: IntoIterator<Item = T>;
+
+    /// Pops multiple `elements` from the front of `self`
+    fn pop_n_front(&mut self, len: usize) -> Option<Self>;
+    /// Pops multiple `elements` from the back of `self`
+    fn pop_n_back(&mut self, len: usize) -> Option<Self>;
+
+    /// Removes the elements within `range` and shifts all following elements to the front, or returns `None` if `range`
+    /// is invalid
+    fn remove_n<Range>(&mut self, range: Range) -> Option<Self> where Range: RangeBounds<usize>;
+
+    /// Moves the elements starting at `at` into a newly allocated instance of `Self`, or returns `None` if `at` is out
+    /// of bounds
+    fn split_off(&mut self, at: usize) -> Option<Self>;
+}
+impl<T, Array> ArrayAllocNewPanic<T> for Array where Array: ArrayAllocNew<T> {
+    fn alloc_new() -> Self {
+        <Self as ArrayAllocNew<T>>::alloc_new().expect("Allocation error")
+    }
+    fn alloc_clone<Source>(elements: &Source) -> Self where Source: ArrayRef<T>, T: Clone {
+        <Self as ArrayAllocNew<T>>::alloc_clone(elements).expect("Allocation error")
+    }
+    fn alloc_from_iter<Elements>(elements: Elements) -> Self where Elements: IntoIterator<Item = T> {
+        <Self as ArrayAllocNew<T>>::alloc_from_iter(elements).expect("Allocation error")
+    }
+
+    fn pop_n_front(&mut self, len: usize) -> Option<Self> {
+        <Self as ArrayAllocNew<T>>::pop_n_front(self, len).expect("Allocation error")
+    }
     fn pop_n_back(&mut self, len: usize) -> Option<Self> {
-        <Self as ArrayAlloc<T>>::pop_n_back(self, len).expect("Allocation error")
+        <Self as ArrayAllocNew<T>>::pop_n_back(self, len).expect("Allocation error")
+    }
+
+    fn remove_n<Range>(&mut self, range: Range) -> Option<Self> where Range: RangeBounds<usize> {
+        <Self as ArrayAllocNew<T>>::remove_n(self, range).expect("Allocation error")
+    }
+
+    fn split_off(&mut self, at: usize) -> Option<Self> {
+        <Self as ArrayAllocNew<T>>::split_off(self, at).expect("Allocation error")
     }
 }
 
 
-/// A trait for types that can perform stack-like memory allocation
-pub trait CanAlloc<T>: Sized {
+/// A trait for types that can perform stack-like memory allocation in place
+///
+///  - Note: The bulk operations have default implementations on top of `push` and `pop`; backends should override them
+///    if they can perform the operation more efficiently.
+pub trait CanAlloc<T>: AsRef<[T]> + AsMut<[T]> + Sized {
     /// An allocation related error
     type Error: Debug;
 
-    /// Creates a new potentially allocated instance of `Self`
-    fn alloc_new() -> Result<Self, Self::Error>;
-
+    /// Pushes an `element` to the end of `self` or returns the error together with the rejected `element`
+    fn try_push(&mut self, element: T) -> Result<(), (Self::Error, T)>;
     /// Pushes an `element` to the end of `self`
-    fn push(&mut self, element: T) -> Result<(), Self::Error>;
+    fn push(&mut self, element: T) -> Result<(), Self::Error> {
+        self.try_push(element).map_err(|(e, _)| e)
+    }
     /// Pops an `element` from the end of `self` if any
     fn pop(&mut self) -> Result<Option<T>, Self::Error>;
+    /// Pushes an `element` to the front of `self` or returns the error together with the rejected `element`
+    fn try_push_front(&mut self, element: T) -> Result<(), (Self::Error, T)> {
+        self.try_push(element)?;
+        self.as_mut().rotate_right(1);
+        Ok(())
+    }
+    /// Pops an `element` from the front of `self` if any
+    fn pop_front(&mut self) -> Result<Option<T>, Self::Error> {
+        // Validate that `self` is not empty
+        if self.as_ref().is_empty() {
+            return Ok(None);
+        }
+
+        self.as_mut().rotate_left(1);
+        self.pop()
+    }
+
+    /// Ensures that `self` can hold at least `additional` more elements
+    fn reserve(&mut self, additional: usize) -> Result<(), Self::Error> {
+        let _ = additional;
+        Ok(())
+    }
+    /// Clones all `elements` to the end of `self`
+    ///
+    ///  - Note: Implementations should use the slice APIs here, which lower to a `memcpy` for `Copy` types
+    fn extend_from_slice(&mut self, elements: &[T]) -> Result<(), Self::Error> where T: Clone {
+        self.reserve(elements.len())?;
+        elements.iter().cloned().try_for_each(|e| self.push(e))
+    }
+    /// Clones the elements within `range` to the end of `self`
+    ///
+    ///  - Note: The caller must ensure that `range` is within the bounds of `self`
+    fn extend_from_within(&mut self, range: Range<usize>) -> Result<(), Self::Error> where T: Clone {
+        self.reserve(range.len())?;
+        for index in range {
+            let element = self.as_ref()[index].clone();
+            self.push(element)?;
+        }
+        Ok(())
+    }
+    /// Shortens `self` to `len` elements if the current length is larger than `len`
+    fn truncate(&mut self, len: usize) -> Result<(), Self::Error> {
+        for _ in 0 .. self.as_ref().len().saturating_sub(len) {
+            let _ = self.pop()?;
+        }
+        Ok(())
+    }
+    /// Moves all elements of `other` to the end of `self`, leaving `other` empty
+    fn append(&mut self, other: &mut Self) -> Result<(), Self::Error> {
+        // Reserve the space first so that no element is moved if the allocation fails
+        let len = self.as_ref().len();
+        self.reserve(other.as_ref().len())?;
+
+        // Move element by element and restore the order
+        while let Some(element) = other.pop()? {
+            self.push(element)?;
+        }
+        self.as_mut()[len..].reverse();
+        Ok(())
+    }
 }
-#[cfg(feature = "std")]
+
+
+/// A trait for types that can perform stack-like memory allocation and create new instances
+pub trait CanAllocNew<T>: CanAlloc<T> {
+    /// Creates a new potentially allocated instance of `Self`
+    fn alloc_new() -> Result<Self, Self::Error>;
+
+    /// Moves the elements starting at `at` into a newly allocated instance of `Self`, or returns `None` if `at` is out
+    /// of bounds
+    fn split_off(&mut self, at: usize) -> Result<Option<Self>, Self::Error> {
+        // Don't mutate `self` if the operation cannot succeed
+        let len = self.as_ref().len();
+        if at > len {
+            return Ok(None);
+        }
+
+        // Move element by element into the new instance and restore the order
+        let mut other = Self::alloc_new()?;
+        other.reserve(len - at)?;
+        for _ in at .. len {
+            let element = self.pop()?.expect("Failed to pop existing element?!");
+            other.push(element)?;
+        }
+        other.as_mut().reverse();
+        Ok(Some(other))
+    }
+}
+#[cfg(feature = "alloc")]
 impl<T> CanAlloc<T> for Vec<T> {
     type Error = WillPanic;
 
+    fn try_push(&mut self, element: T) -> Result<(), (Self::Error, T)> {
+        self.push(element);
+        Ok(())
+    }
+    fn pop(&mut self) -> Result<Option<T>, Self::Error> {
+        Ok(self.pop())
+    }
+
+    fn reserve(&mut self, additional: usize) -> Result<(), Self::Error> {
+        Vec::reserve(self, additional);
+        Ok(())
+    }
+    fn extend_from_slice(&mut self, elements: &[T]) -> Result<(), Self::Error> where T: Clone {
+        Vec::extend_from_slice(self, elements);
+        Ok(())
+    }
+    fn extend_from_within(&mut self, range: Range<usize>) -> Result<(), Self::Error> where T: Clone {
+        Vec::extend_from_within(self, range);
+        Ok(())
+    }
+    fn truncate(&mut self, len: usize) -> Result<(), Self::Error> {
+        Vec::truncate(self, len);
+        Ok(())
+    }
+    fn append(&mut self, other: &mut Self) -> Result<(), Self::Error> {
+        Vec::append(self, other);
+        Ok(())
+    }
+}
+#[cfg(feature = "alloc")]
+impl<T> CanAllocNew<T> for Vec<T> {
     fn alloc_new() -> Result<Self, Self::Error> {
         Ok(Self::new())
     }
 
-    fn push(&mut self, element: T) -> Result<(), Self::Error> {
-        Ok(self.push(element))
+    fn split_off(&mut self, at: usize) -> Result<Option<Self>, Self::Error> {
+        match at <= self.len() {
+            true => Ok(Some(Vec::split_off(self, at))),
+            false => Ok(None)
+        }
+    }
+}
+impl<T, Backend> CanAlloc<T> for &mut Backend where Backend: CanAlloc<T> {
+    type Error = Backend::Error;
+
+    fn try_push(&mut self, element: T) -> Result<(), (Self::Error, T)> {
+        (**self).try_push(element)
     }
     fn pop(&mut self) -> Result<Option<T>, Self::Error> {
-        Ok(self.pop())
+        (**self).pop()
+    }
+    fn try_push_front(&mut self, element: T) -> Result<(), (Self::Error, T)> {
+        (**self).try_push_front(element)
+    }
+    fn pop_front(&mut self) -> Result<Option<T>, Self::Error> {
+        (**self).pop_front()
+    }
+
+    fn reserve(&mut self, additional: usize) -> Result<(), Self::Error> {
+        (**self).reserve(additional)
+    }
+    fn extend_from_slice(&mut self, elements: &[T]) -> Result<(), Self::Error> where T: Clone {
+        (**self).extend_from_slice(elements)
+    }
+    fn extend_from_within(&mut self, range: Range<usize>) -> Result<(), Self::Error> where T: Clone {
+        (**self).extend_from_within(range)
+    }
+    fn truncate(&mut self, len: usize) -> Result<(), Self::Error> {
+        (**self).truncate(len)
+    }
+    fn append(&mut self, other: &mut Self) -> Result<(), Self::Error> {
+        (**self).append(other)
+    }
+}
+
+
+/// A trait for referencable linear array types which store their elements in two segments
+///
+/// __Discussion:__ In contrast to `ArrayRef`, this trait does not require the elements to be stored contiguously, which
+/// allows types like `VecDeque` to offer their cheap front operations.
+pub trait ArraySegmentedRef<T> {
+    /// The underlying elements as front and back segment
+    fn as_slices(&self) -> (&[T], &[T]);
+    /// The length of the wrapped elements
+    fn len(&self) -> usize;
+    /// Whether `self` is empty or not
+    fn is_empty(&self) -> bool;
+
+    /// Gets an element
+    fn get(&self, index: usize) -> Option<&T>;
+    /// Gets a subrange as front and back segment
+    fn get_n<Range>(&self, range: Range) -> Option<ArraySplit<'_, T>> where Range: RangeBounds<usize>;
+
+    /// Returns a reference to the first element
+    fn first(&self) -> Option<&T>;
+    /// Returns a reference to the last element
+    fn last(&self) -> Option<&T>;
+
+    /// Returns an iterator that references the elements
+    fn iter(&self) -> Chain<SliceIter<'_, T>, SliceIter<'_, T>>;
+
+    /// Gets an element or returns a descriptive error
+    fn try_get(&self, index: usize) -> Result<&T, ArrayError> {
+        let len = self.len();
+        self.get(index).ok_or(ArrayError::OutOfBounds { index, len })
+    }
+}
+
+
+/// A trait for mutably referencable linear array types which store their elements in two segments
+pub trait ArraySegmentedMut<T>: ArraySegmentedRef<T> {
+    /// The underlying elements as mutable front and back segment
+    fn as_slices_mut(&mut self) -> (&mut [T], &mut [T]);
+
+    /// Gets a mutable element
+    fn get_mut(&mut self, index: usize) -> Option<&mut T>;
+    /// Gets a mutable subrange as front and back segment
+    fn get_n_mut<Range>(&mut self, range: Range) -> Option<ArraySplitMut<'_, T>> where Range: RangeBounds<usize>;
+
+    /// Returns a mutable reference to the first element
+    fn first_mut(&mut self) -> Option<&mut T>;
+    /// Returns a mutable reference to the last element
+    fn last_mut(&mut self) -> Option<&mut T>;
+
+    /// Returns an iterator that mutably references the elements
+    fn iter_mut(&mut self) -> Chain<SliceIterMut<'_, T>, SliceIterMut<'_, T>>;
+
+    /// Gets a mutable element or returns a descriptive error
+    fn try_get_mut(&mut self, index: usize) -> Result<&mut T, ArrayError> {
+        let len = self.len();
+        self.get_mut(index).ok_or(ArrayError::OutOfBounds { index, len })
+    }
+}
+
+
+/// A trait for allocatable/resizeable linear array types which store their elements in two segments
+///
+///  - Note: In contrast to `ArrayAlloc`, the front operations don't need to shift the elements
+pub trait ArraySegmentedAlloc<T>: ArraySegmentedMut<T> + Sized {
+    /// An alloc related error
+    type Error: Debug;
+
+    /// Grows `self` to the given capacity if the current length is smaller than `len` and inits new elements using `init`
+    fn grow_with(&mut self, len: usize, init: impl FnMut() -> T) -> Result<(), Self::Error>;
+    /// Grows `self` to the given capacity if the current length is smaller than `len` and inits new elements using
+    /// `Default`
+    fn grow(&mut self, len: usize) -> Result<(), Self::Error> where T: Default;
+    /// Shrinks `self` to the given capacity if the current length is larger than `len`
+    fn shrink(&mut self, len: usize) -> Result<(), Self::Error>;
+
+    /// Pushes an `element` to the front of `self`
+    fn push_front(&mut self, element: T) -> Result<(), Self::Error>;
+    /// Pushes an `element` to the back of `self`
+    fn push_back(&mut self, element: T) -> Result<(), Self::Error>;
+    /// Pops an `element` from the front of `self`
+    fn pop_front(&mut self) -> Result<Option<T>, Self::Error>;
+    /// Pops an `element` from the back of `self`
+    fn pop_back(&mut self) -> Result<Option<T>, Self::Error>;
+}
+
+
+/// An infallible/panicking variant of `ArraySegmentedAlloc`
+///
+///  - Note: See `ArrayAllocPanic` for a discussion of the panic policy
+pub trait ArraySegmentedAllocPanic<T>: ArraySegmentedMut<T> + Sized {
+    /// Grows `self` to the given capacity if the current length is smaller than `len` and inits new elements using `init`
+    fn grow_with(&mut self, len: usize, init: impl FnMut() -> T);
+    /// Grows `self` to the given capacity if the current length is smaller than `len` and inits new elements using
+    /// `Default`
+    fn grow(&mut self, len: usize) where T: Default;
+    /// Shrinks `self` to the given capacity if the current length is larger than `len`
+    fn shrink(&mut self, len: usize);
+
+    /// Pushes an `element` to the front of `self`
+    fn push_front(&mut self, element: T);
+    /// Pushes an `element` to the back of `self`
+    fn push_back(&mut self, element: T);
+    /// Pops an `element` from the front of `self`
+    fn pop_front(&mut self) -> Option<T>;
+    /// Pops an `element` from the back of `self`
+    fn pop_back(&mut self) -> Option<T>;
+}
+impl<T, Array> ArraySegmentedAllocPanic<T> for Array where Array: ArraySegmentedAlloc<T> {
+    fn grow_with(&mut self, len: usize, init: impl FnMut() -> T) {
+        <Self as ArraySegmentedAlloc<T>>::grow_with(self, len, init).expect("Allocation error")
+    }
+    fn grow(&mut self, len: usize) where T: Default {
+        <Self as ArraySegmentedAlloc<T>>::grow(self, len).expect("Allocation error")
+    }
+    fn shrink(&mut self, len: usize) {
+        <Self as ArraySegmentedAlloc<T>>::shrink(self, len).expect("Allocation error")
+    }
+
+    fn push_front(&mut self, element: T) {
+        <Self as ArraySegmentedAlloc<T>>::push_front(self, element).expect("Allocation error")
+    }
+    fn push_back(&mut self, element: T) {
+        <Self as ArraySegmentedAlloc<T>>::push_back(self, element).expect("Allocation error")
+    }
+    fn pop_front(&mut self) -> Option<T> {
+        <Self as ArraySegmentedAlloc<T>>::pop_front(self).expect("Allocation error")
+    }
+    fn pop_back(&mut self) -> Option<T> {
+        <Self as ArraySegmentedAlloc<T>>::pop_back(self).expect("Allocation error")
+    }
+}
+
+
+/// A trait for types that store their elements in a front and a back segment
+pub trait AsSlices<T> {
+    /// The elements as front and back segment
+    fn as_slices(&self) -> (&[T], &[T]);
+    /// The elements as mutable front and back segment
+    fn as_slices_mut(&mut self) -> (&mut [T], &mut [T]);
+}
+#[cfg(feature = "alloc")]
+impl<T> AsSlices<T> for VecDeque<T> {
+    fn as_slices(&self) -> (&[T], &[T]) {
+        VecDeque::as_slices(self)
+    }
+    fn as_slices_mut(&mut self) -> (&mut [T], &mut [T]) {
+        VecDeque::as_mut_slices(self)
+    }
+}
+
+
+/// A trait for segmented types that can perform deque-like memory allocation in place
+pub trait CanAllocSegmented<T>: AsSlices<T> + Sized {
+    /// An allocation related error
+    type Error: Debug;
+
+    /// Pushes an `element` to the front of `self` or returns the error together with the rejected `element`
+    fn try_push_front(&mut self, element: T) -> Result<(), (Self::Error, T)>;
+    /// Pushes an `element` to the back of `self` or returns the error together with the rejected `element`
+    fn try_push_back(&mut self, element: T) -> Result<(), (Self::Error, T)>;
+    /// Pops an `element` from the front of `self` if any
+    fn pop_front(&mut self) -> Result<Option<T>, Self::Error>;
+    /// Pops an `element` from the back of `self` if any
+    fn pop_back(&mut self) -> Result<Option<T>, Self::Error>;
+
+    /// Ensures that `self` can hold at least `additional` more elements
+    fn reserve(&mut self, additional: usize) -> Result<(), Self::Error> {
+        let _ = additional;
+        Ok(())
+    }
+    /// Shortens `self` to `len` elements if the current length is larger than `len`
+    fn truncate(&mut self, len: usize) -> Result<(), Self::Error> {
+        let (front, back) = self.as_slices();
+        for _ in 0 .. (front.len() + back.len()).saturating_sub(len) {
+            let _ = self.pop_back()?;
+        }
+        Ok(())
+    }
+}
+#[cfg(feature = "alloc")]
+impl<T> CanAllocSegmented<T> for VecDeque<T> {
+    type Error = WillPanic;
+
+    fn try_push_front(&mut self, element: T) -> Result<(), (Self::Error, T)> {
+        VecDeque::push_front(self, element);
+        Ok(())
+    }
+    fn try_push_back(&mut self, element: T) -> Result<(), (Self::Error, T)> {
+        VecDeque::push_back(self, element);
+        Ok(())
+    }
+    fn pop_front(&mut self) -> Result<Option<T>, Self::Error> {
+        Ok(VecDeque::pop_front(self))
+    }
+    fn pop_back(&mut self) -> Result<Option<T>, Self::Error> {
+        Ok(VecDeque::pop_back(self))
+    }
+
+    fn reserve(&mut self, additional: usize) -> Result<(), Self::Error> {
+        VecDeque::reserve(self, additional);
+        Ok(())
+    }
+    fn truncate(&mut self, len: usize) -> Result<(), Self::Error> {
+        VecDeque::truncate(self, len);
+        Ok(())
     }
 }
\ No newline at end of file
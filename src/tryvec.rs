@@ -0,0 +1,141 @@
+use crate::{
+    misc::AllocError, traits::{ CanAlloc, CanAllocNew },
+    std::{
+        ops::Range,
+        vec::{ Vec, IntoIter as VecIntoIter },
+        slice::{ Iter as SliceIter, IterMut as SliceIterMut }
+    }
+};
+
+
+/// A wrapper that implements `CanAlloc` over a `Vec` and reports allocation failures instead of panicking
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TryVec<T> {
+    /// The wrapped vector
+    vec: Vec<T>
+}
+impl<T> TryVec<T> {
+    /// Creates a new empty `TryVec` instance
+    pub const fn new() -> Self {
+        Self { vec: Vec::new() }
+    }
+    /// Creates a new empty `TryVec` instance with a capacity of at least `capacity` elements
+    pub fn with_capacity(capacity: usize) -> Result<Self, AllocError> {
+        let mut this = Self::new();
+        this.try_reserve(capacity)?;
+        Ok(this)
+    }
+
+    /// The amount of elements `self` can hold without reallocating
+    pub fn capacity(&self) -> usize {
+        self.vec.capacity()
+    }
+    /// Reserves capacity for at least `additional` more elements
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), AllocError> {
+        self.vec.try_reserve(additional).map_err(|_| {
+            let requested = self.vec.len().saturating_add(additional);
+            AllocError { requested }
+        })
+    }
+
+    /// Returns the wrapped vector
+    #[inline(always)]
+    pub fn into_inner(self) -> Vec<T> {
+        self.vec
+    }
+}
+impl<T> From<Vec<T>> for TryVec<T> {
+    fn from(vec: Vec<T>) -> Self {
+        Self { vec }
+    }
+}
+impl<T> AsRef<[T]> for TryVec<T> {
+    fn as_ref(&self) -> &[T] {
+        &self.vec
+    }
+}
+impl<T> AsMut<[T]> for TryVec<T> {
+    fn as_mut(&mut self) -> &mut [T] {
+        &mut self.vec
+    }
+}
+impl<T> CanAlloc<T> for TryVec<T> {
+    type Error = AllocError;
+
+    fn try_push(&mut self, element: T) -> Result<(), (Self::Error, T)> {
+        // Reserve the space first so that `Vec::push` never needs to reallocate
+        if let Err(e) = self.try_reserve(1) {
+            return Err((e, element));
+        }
+
+        self.vec.push(element);
+        Ok(())
+    }
+    fn pop(&mut self) -> Result<Option<T>, Self::Error> {
+        Ok(self.vec.pop())
+    }
+
+    fn reserve(&mut self, additional: usize) -> Result<(), Self::Error> {
+        self.try_reserve(additional)
+    }
+    fn extend_from_slice(&mut self, elements: &[T]) -> Result<(), Self::Error> where T: Clone {
+        self.try_reserve(elements.len())?;
+        self.vec.extend_from_slice(elements);
+        Ok(())
+    }
+    fn extend_from_within(&mut self, range: Range<usize>) -> Result<(), Self::Error> where T: Clone {
+        self.try_reserve(range.len())?;
+        self.vec.extend_from_within(range);
+        Ok(())
+    }
+    fn truncate(&mut self, len: usize) -> Result<(), Self::Error> {
+        self.vec.truncate(len);
+        Ok(())
+    }
+    fn append(&mut self, other: &mut Self) -> Result<(), Self::Error> {
+        self.try_reserve(other.vec.len())?;
+        self.vec.append(&mut other.vec);
+        Ok(())
+    }
+}
+impl<T> CanAllocNew<T> for TryVec<T> {
+    fn alloc_new() -> Result<Self, Self::Error> {
+        Ok(Self::new())
+    }
+
+    fn split_off(&mut self, at: usize) -> Result<Option<Self>, Self::Error> {
+        // Don't mutate `self` if the operation cannot succeed
+        if at > self.vec.len() {
+            return Ok(None);
+        }
+
+        // Allocate the new instance first so that `Vec::extend` never needs to reallocate
+        let mut other = Self::with_capacity(self.vec.len() - at)?;
+        other.vec.extend(self.vec.drain(at..));
+        Ok(Some(other))
+    }
+}
+impl<T> IntoIterator for TryVec<T> {
+    type Item = T;
+    type IntoIter = VecIntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.vec.into_iter()
+    }
+}
+impl<'a, T> IntoIterator for &'a TryVec<T> {
+    type Item = &'a T;
+    type IntoIter = SliceIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_ref().iter()
+    }
+}
+impl<'a, T> IntoIterator for &'a mut TryVec<T> {
+    type Item = &'a mut T;
+    type IntoIter = SliceIterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_mut().iter_mut()
+    }
+}
\ No newline at end of file
@@ -1,16 +1,26 @@
 use crate::{
-    misc::{ BufferTooSmall, RangeBoundsExt },
-    traits::{ ArrayRef, ArrayMut, ArrayAlloc, CanAlloc },
+    misc::{ ArrayError, BufferTooSmall, LengthMismatch, WillPanic, RangeBoundsExt },
+    traits::{
+        ArrayRef, ArrayMut, ArrayAlloc, ArrayAllocNew, ArraySplit, ArraySplitMut, CanAlloc, CanAllocNew,
+        ArraySegmentedRef, ArraySegmentedMut, ArraySegmentedAlloc, AsSlices, CanAllocSegmented
+    },
+    iter::{ Chunks, ChunksExact, RChunks, Windows, ChunksMut, ChunksExactMut, Drain },
     std::{
-        cmp::Ordering, ops::RangeBounds,
+        mem, cmp::{ self, Ordering }, ops::RangeBounds,
         fmt::{ self, Debug, Formatter },
         hash::{ Hash, Hasher },
-        slice::{ Iter as SliceIter, IterMut as SliceIterMut }
+        iter::{ Chain, FromIterator }, slice::{ Iter as SliceIter, IterMut as SliceIterMut }
     }
 };
 
 
 /// A wrapper for array types that exposes checked APIs only
+///
+/// __Discussion:__ `for element in &array` works if the wrapped type can be iterated by reference, which is the case
+/// for `Vec`, `VecDeque`, `TryVec`, `InlineArray`, `SmallArray`, `PreallocatedUninit` and `Preallocated` (if the buffer
+/// implements `BufferElement`). The slice views (`Array<&[T]>` and `Array<&mut [T]>`) returned by e.g. `get_n`,
+/// `split_at` or the chunk iterators are not covered because a reference to a slice reference is not iterable; use
+/// `iter`/`iter_mut` or iterate the view by value instead.
 pub struct Array<Wrapped> {
     /// The wrapped element
     wrapped: Wrapped
@@ -26,6 +36,11 @@ impl<Wrapped> Array<Wrapped> {
     pub fn into_inner(self) -> Wrapped {
         self.wrapped
     }
+    /// Returns a reference to the wrapped array
+    #[inline(always)]
+    pub(in crate) fn inner(&self) -> &Wrapped {
+        &self.wrapped
+    }
 }
 impl<Wrapped> AsRef<Array<Wrapped>> for Array<Wrapped> {
     fn as_ref(&self) -> &Array<Wrapped> {
@@ -59,20 +74,102 @@ impl<T, Wrapped> ArrayRef<T> for Array<Wrapped> where Wrapped: AsRef<[T]> {
         self.as_slice().last()
     }
 
-    fn iter(&self) -> SliceIter<T> {
+    fn split_at(&self, mid: usize) -> Option<ArraySplit<'_, T>> {
+        // Validate `mid`
+        let slice = self.as_slice();
+        if mid > slice.len() {
+            return None;
+        }
+
+        let (left, right) = slice.split_at(mid);
+        Some((Array::new(left), Array::new(right)))
+    }
+    fn split_first(&self) -> Option<(&T, Array<&[T]>)> {
+        let (first, rest) = self.as_slice().split_first()?;
+        Some((first, Array::new(rest)))
+    }
+    fn split_first_n(&self, len: usize) -> Option<ArraySplit<'_, T>> {
+        self.split_at(len)
+    }
+    fn split_last(&self) -> Option<(&T, Array<&[T]>)> {
+        let (last, rest) = self.as_slice().split_last()?;
+        Some((last, Array::new(rest)))
+    }
+    fn split_last_n(&self, len: usize) -> Option<ArraySplit<'_, T>> {
+        let mid = self.len().checked_sub(len)?;
+        let (rest, last) = self.split_at(mid)?;
+        Some((last, rest))
+    }
+
+    fn iter(&self) -> SliceIter<'_, T> {
         self.as_slice().iter()
     }
+    fn chunks(&self, size: usize) -> Option<Chunks<'_, T>> {
+        match size {
+            0 => None,
+            size => Some(Chunks::new(self.as_slice().chunks(size)))
+        }
+    }
+    fn chunks_exact(&self, size: usize) -> Option<ChunksExact<'_, T>> {
+        match size {
+            0 => None,
+            size => Some(ChunksExact::new(self.as_slice().chunks_exact(size)))
+        }
+    }
+    fn rchunks(&self, size: usize) -> Option<RChunks<'_, T>> {
+        match size {
+            0 => None,
+            size => Some(RChunks::new(self.as_slice().rchunks(size)))
+        }
+    }
+    fn windows(&self, size: usize) -> Option<Windows<'_, T>> {
+        match size {
+            0 => None,
+            size => Some(Windows::new(self.as_slice().windows(size)))
+        }
+    }
 
     fn clone_to<Source>(&self, dest: &mut Source) -> Result<(), BufferTooSmall> where Source: ArrayMut<T>, T: Clone {
         // Validate length
-        if self.len() > dest.len() {
-            Err(BufferTooSmall)?;
-        }
+        let (needed, available) = (self.len(), dest.len());
+        let dest = dest.as_slice_mut().get_mut(..needed).ok_or(BufferTooSmall { needed, available })?;
 
         // Clone the source elements to dest
-        dest.iter_mut().zip(self.iter()).for_each(|(t, e)| *t = e.clone());
+        dest.clone_from_slice(self.as_slice());
+        Ok(())
+    }
+    fn clone_to_exact<Target>(&self, target: &mut Target) -> Result<(), LengthMismatch>
+        where Target: ArrayMut<T>, T: Clone
+    {
+        // Validate length
+        if self.len() != target.len() {
+            Err(LengthMismatch)?;
+        }
+
+        target.as_slice_mut().clone_from_slice(self.as_slice());
+        Ok(())
+    }
+    fn copy_to_exact<Target>(&self, target: &mut Target) -> Result<(), LengthMismatch>
+        where Target: ArrayMut<T>, T: Copy
+    {
+        // Validate length
+        if self.len() != target.len() {
+            Err(LengthMismatch)?;
+        }
+
+        target.as_slice_mut().copy_from_slice(self.as_slice());
         Ok(())
     }
+    fn clone_to_prefix<Target>(&self, target: &mut Target) -> usize where Target: ArrayMut<T>, T: Clone {
+        let len = cmp::min(self.len(), target.len());
+        target.as_slice_mut()[..len].clone_from_slice(&self.as_slice()[..len]);
+        len
+    }
+    fn copy_to_prefix<Target>(&self, target: &mut Target) -> usize where Target: ArrayMut<T>, T: Copy {
+        let len = cmp::min(self.len(), target.len());
+        target.as_slice_mut()[..len].copy_from_slice(&self.as_slice()[..len]);
+        len
+    }
 }
 impl<T, Wrapped> ArrayMut<T> for Array<Wrapped> where Wrapped: AsRef<[T]> + AsMut<[T]> {
     fn as_slice_mut(&mut self) -> &mut [T] {
@@ -95,9 +192,168 @@ impl<T, Wrapped> ArrayMut<T> for Array<Wrapped> where Wrapped: AsRef<[T]> + AsMu
         self.as_slice_mut().last_mut()
     }
 
-    fn iter_mut(&mut self) -> SliceIterMut<T> {
+    fn split_at_mut(&mut self, mid: usize) -> Option<ArraySplitMut<'_, T>> {
+        // Validate `mid`
+        let slice = self.as_slice_mut();
+        if mid > slice.len() {
+            return None;
+        }
+
+        let (left, right) = slice.split_at_mut(mid);
+        Some((Array::new(left), Array::new(right)))
+    }
+    fn split_first_mut(&mut self) -> Option<(&mut T, Array<&mut [T]>)> {
+        let (first, rest) = self.as_slice_mut().split_first_mut()?;
+        Some((first, Array::new(rest)))
+    }
+    fn split_first_n_mut(&mut self, len: usize) -> Option<ArraySplitMut<'_, T>> {
+        self.split_at_mut(len)
+    }
+    fn split_last_mut(&mut self) -> Option<(&mut T, Array<&mut [T]>)> {
+        let (last, rest) = self.as_slice_mut().split_last_mut()?;
+        Some((last, Array::new(rest)))
+    }
+    fn split_last_n_mut(&mut self, len: usize) -> Option<ArraySplitMut<'_, T>> {
+        let mid = self.len().checked_sub(len)?;
+        let (rest, last) = self.split_at_mut(mid)?;
+        Some((last, rest))
+    }
+
+    fn iter_mut(&mut self) -> SliceIterMut<'_, T> {
         self.as_slice_mut().iter_mut()
     }
+    fn chunks_mut(&mut self, size: usize) -> Option<ChunksMut<'_, T>> {
+        match size {
+            0 => None,
+            size => Some(ChunksMut::new(self.as_slice_mut().chunks_mut(size)))
+        }
+    }
+    fn chunks_exact_mut(&mut self, size: usize) -> Option<ChunksExactMut<'_, T>> {
+        match size {
+            0 => None,
+            size => Some(ChunksExactMut::new(self.as_slice_mut().chunks_exact_mut(size)))
+        }
+    }
+
+    fn clone_from_exact<Source>(&mut self, source: &Source) -> Result<(), LengthMismatch>
+        where Source: ArrayRef<T>, T: Clone
+    {
+        // Validate length
+        if self.len() != source.len() {
+            Err(LengthMismatch)?;
+        }
+
+        self.as_slice_mut().clone_from_slice(source.as_slice());
+        Ok(())
+    }
+    fn copy_from_exact<Source>(&mut self, source: &Source) -> Result<(), LengthMismatch>
+        where Source: ArrayRef<T>, T: Copy
+    {
+        // Validate length
+        if self.len() != source.len() {
+            Err(LengthMismatch)?;
+        }
+
+        self.as_slice_mut().copy_from_slice(source.as_slice());
+        Ok(())
+    }
+    fn clone_from_prefix<Source>(&mut self, source: &Source) -> usize where Source: ArrayRef<T>, T: Clone {
+        let len = cmp::min(self.len(), source.len());
+        self.as_slice_mut()[..len].clone_from_slice(&source.as_slice()[..len]);
+        len
+    }
+    fn copy_from_prefix<Source>(&mut self, source: &Source) -> usize where Source: ArrayRef<T>, T: Copy {
+        let len = cmp::min(self.len(), source.len());
+        self.as_slice_mut()[..len].copy_from_slice(&source.as_slice()[..len]);
+        len
+    }
+
+    fn swap(&mut self, a: usize, b: usize) -> Option<()> {
+        // Validate the indices
+        let len = self.len();
+        if a >= len || b >= len {
+            return None;
+        }
+
+        self.as_slice_mut().swap(a, b);
+        Some(())
+    }
+    fn swap_ranges<RangeA, RangeB>(&mut self, a: RangeA, b: RangeB) -> Option<()>
+        where RangeA: RangeBounds<usize>, RangeB: RangeBounds<usize>
+    {
+        // Validate the ranges
+        let len = self.len();
+        let a = a.into_absolute(0, len).filter(|a| a.end <= len)?;
+        let b = b.into_absolute(0, len).filter(|b| b.end <= len)?;
+        if a.len() != b.len() {
+            return None;
+        }
+
+        // Order the ranges and ensure that they don't overlap
+        let (lower, upper) = match a.start <= b.start {
+            true => (a, b),
+            false => (b, a)
+        };
+        if lower.end > upper.start {
+            return None;
+        }
+
+        // Split the slice between the ranges and swap them
+        let (left, right) = self.as_slice_mut().split_at_mut(upper.start);
+        left[lower].swap_with_slice(&mut right[..upper.len()]);
+        Some(())
+    }
+    fn replace(&mut self, index: usize, element: T) -> Result<T, T> {
+        match self.get_mut(index) {
+            Some(slot) => Ok(mem::replace(slot, element)),
+            None => Err(element)
+        }
+    }
+    fn take(&mut self, index: usize) -> Option<T> where T: Default {
+        self.get_mut(index).map(mem::take)
+    }
+
+    fn copy_within<Range>(&mut self, source: Range, dest: usize) -> Option<()>
+        where Range: RangeBounds<usize>, T: Copy
+    {
+        // Validate the ranges
+        let len = self.len();
+        let source = source.into_absolute(0, len).filter(|source| source.end <= len)?;
+        dest.checked_add(source.len()).filter(|end| *end <= len)?;
+
+        self.as_slice_mut().copy_within(source, dest);
+        Some(())
+    }
+    fn clone_within<Range>(&mut self, source: Range, dest: usize) -> Option<()>
+        where Range: RangeBounds<usize>, T: Clone
+    {
+        // Validate the ranges
+        let len = self.len();
+        let source = source.into_absolute(0, len).filter(|source| source.end <= len)?;
+        dest.checked_add(source.len()).filter(|end| *end <= len)?;
+
+        // Clone in an order that does not overwrite source elements before they are cloned
+        let slice = self.as_slice_mut();
+        let offsets = 0 .. source.len();
+        let mut clone_at = |offset: usize| slice[dest + offset] = slice[source.start + offset].clone();
+        match dest <= source.start {
+            true => offsets.for_each(&mut clone_at),
+            false => offsets.rev().for_each(&mut clone_at)
+        }
+        Some(())
+    }
+
+    fn fill(&mut self, value: T) where T: Clone {
+        self.as_slice_mut().fill(value)
+    }
+    fn fill_with(&mut self, init: impl FnMut() -> T) {
+        self.as_slice_mut().fill_with(init)
+    }
+    fn fill_range<Range>(&mut self, range: Range, value: T) -> Option<()> where Range: RangeBounds<usize>, T: Clone {
+        let mut elements = self.get_n_mut(range)?;
+        elements.fill(value);
+        Some(())
+    }
 
     fn rotate_left(&mut self, count: usize) {
         // Avoid division by zero
@@ -124,17 +380,10 @@ impl<T, Wrapped> ArrayMut<T> for Array<Wrapped> where Wrapped: AsRef<[T]> + AsMu
 impl<T, Wrapped> ArrayAlloc<T> for Array<Wrapped> where Wrapped: AsRef<[T]> + AsMut<[T]> + CanAlloc<T> {
     type Error = Wrapped::Error;
     
-    fn alloc_new() -> Result<Self, Self::Error> {
-        Ok(Self::new(Wrapped::alloc_new()?))
-    }
-    fn alloc_clone<Source>(elements: &Source) -> Result<Self, Self::Error> where Source: ArrayRef<T>, T: Clone {
-        let mut this = Self::alloc_new()?;
-        this.push_n_back(elements)?;
-        Ok(this)
-    }
-    
     fn grow_with(&mut self, len: usize, mut init: impl FnMut() -> T) -> Result<(), Self::Error> {
-        for _ in 0 .. len.saturating_sub(self.len()) {
+        let additional = len.saturating_sub(self.len());
+        self.wrapped.reserve(additional)?;
+        for _ in 0 .. additional {
             self.push_back(init())?;
         }
         Ok(())
@@ -143,16 +392,34 @@ impl<T, Wrapped> ArrayAlloc<T> for Array<Wrapped> where Wrapped: AsRef<[T]> + As
         self.grow_with(len, T::default)
     }
     fn shrink(&mut self, len: usize) -> Result<(), Self::Error> {
-        for _ in 0 .. self.len().saturating_sub(len) {
-            let _ = self.wrapped.pop()?;
+        self.wrapped.truncate(len)
+    }
+    fn grow_exact(&mut self, len: usize) -> Result<(), ArrayError<Self::Error>> where T: Default {
+        // Validate the direction
+        if len < self.len() {
+            Err(ArrayError::InvalidLength { requested: len, len: self.len() })?;
+        }
+        Ok(self.grow(len)?)
+    }
+    fn shrink_exact(&mut self, len: usize) -> Result<(), ArrayError<Self::Error>> {
+        // Validate the direction
+        if len > self.len() {
+            Err(ArrayError::InvalidLength { requested: len, len: self.len() })?;
+        }
+        Ok(self.shrink(len)?)
+    }
+    fn resize(&mut self, len: usize, value: T) -> Result<(), Self::Error> where T: Clone {
+        self.resize_with(len, || value.clone())
+    }
+    fn resize_with(&mut self, len: usize, init: impl FnMut() -> T) -> Result<(), Self::Error> {
+        match len > self.len() {
+            true => self.grow_with(len, init),
+            false => self.shrink(len)
         }
-        Ok(())
     }
 
     fn push_front(&mut self, element: T) -> Result<(), Self::Error> {
-        self.push_back(element)?;
-        self.rotate_right(1);
-        Ok(())
+        self.wrapped.try_push_front(element).map_err(|(e, _)| e)
     }
     fn push_n_front<Source>(&mut self, elements: &Source) -> Result<(), Self::Error>
         where Source: ArrayRef<T>, T: Clone
@@ -167,37 +434,362 @@ impl<T, Wrapped> ArrayAlloc<T> for Array<Wrapped> where Wrapped: AsRef<[T]> + As
     fn push_n_back<Source>(&mut self, elements: &Source) -> Result<(), Self::Error>
         where Source: ArrayRef<T>, T: Clone
     {
-        elements.iter().cloned().try_for_each(|e| self.push_back(e))
+        self.wrapped.extend_from_slice(elements.as_slice())
     }
 
-    fn pop_front(&mut self) -> Result<Option<T>, Self::Error> {
-        self.rotate_left(1);
-        self.pop_back()
+    fn extend_from_within<Range>(&mut self, range: Range) -> Result<Option<()>, Self::Error>
+        where Range: RangeBounds<usize>, T: Clone
+    {
+        // Don't mutate `self` if the operation cannot succeed
+        let len = self.len();
+        let range = match range.into_absolute(0, len) {
+            Some(range) if range.end <= len => range,
+            _ => return Ok(None)
+        };
+
+        self.wrapped.extend_from_within(range)?;
+        Ok(Some(()))
     }
-    fn pop_n_front(&mut self, len: usize) -> Result<Option<Self>, Self::Error> {
-        self.rotate_left(len);
-        self.pop_n_back(len)
+
+    fn try_extend<Elements>(&mut self, elements: Elements) -> Result<(), (Self::Error, T)>
+        where Elements: IntoIterator<Item = T>
+    {
+        elements.into_iter().try_for_each(|element| self.wrapped.try_push(element))
+    }
+    fn try_extend_all<Elements>(&mut self, elements: Elements) -> Result<(), Self::Error>
+        where Elements: IntoIterator<Item = T>
+    {
+        // Check the capacity before consuming any element
+        let elements = elements.into_iter();
+        self.wrapped.reserve(elements.size_hint().0)?;
+
+        // Push the elements and restore the original length if an element is rejected
+        let len = self.len();
+        if let Err((e, _)) = self.try_extend(elements) {
+            self.shrink(len)?;
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    fn pop_front(&mut self) -> Result<Option<T>, Self::Error> {
+        self.wrapped.pop_front()
     }
     fn pop_back(&mut self) -> Result<Option<T>, Self::Error> {
         self.wrapped.pop()
     }
-    fn pop_n_back(&mut self, len: usize) -> Result<Option<Self>, Self::Error> {
+
+    fn insert(&mut self, index: usize, element: T) -> Result<Option<()>, Self::Error> {
         // Don't mutate `self` if the operation cannot succeed
-        if self.len() < len {
-            return Ok(None)
+        if index > self.len() {
+            return Ok(None);
         }
 
-        // Move element by element into the new array
-        let mut popped = Self::alloc_new()?;
-        for _ in 0 .. len {
-            let element = self.pop_back()?.expect("Failed to pop existing element?!");
-            popped.push_back(element)?;
+        // Append the element and rotate it into place
+        self.push_back(element)?;
+        self.as_slice_mut()[index..].rotate_right(1);
+        Ok(Some(()))
+    }
+    fn insert_n<Source>(&mut self, index: usize, elements: &Source) -> Result<Option<()>, Self::Error>
+        where Source: ArrayRef<T>, T: Clone
+    {
+        // Don't mutate `self` if the operation cannot succeed
+        if index > self.len() {
+            return Ok(None);
+        }
+
+        // Append the elements and rotate them into place
+        self.push_n_back(elements)?;
+        self.as_slice_mut()[index..].rotate_right(elements.len());
+        Ok(Some(()))
+    }
+    fn remove(&mut self, index: usize) -> Result<Option<T>, Self::Error> {
+        // Don't mutate `self` if the operation cannot succeed
+        if index >= self.len() {
+            return Ok(None);
+        }
+
+        // Rotate the element to the end and pop it
+        self.as_slice_mut()[index..].rotate_left(1);
+        self.pop_back()
+    }
+    fn swap_remove(&mut self, index: usize) -> Result<Option<T>, Self::Error> {
+        // Don't mutate `self` if the operation cannot succeed
+        let last = match self.len().checked_sub(1) {
+            Some(last) if index <= last => last,
+            _ => return Ok(None)
+        };
+
+        // Swap the element with the last element and pop it
+        self.as_slice_mut().swap(index, last);
+        self.pop_back()
+    }
+    fn drain<Range>(&mut self, range: Range) -> Option<Drain<'_, T, Self>> where Range: RangeBounds<usize> {
+        // Validate the range
+        let len = self.len();
+        let range = range.into_absolute(0, len).filter(|range| range.end <= len)?;
+
+        // Move the drained elements to the end in reverse order so that they can be popped in order
+        let count = range.len();
+        let tail = &mut self.as_slice_mut()[range.start..];
+        tail[..count].reverse();
+        tail.rotate_left(count);
+        Some(Drain::new(self, count))
+    }
+
+    fn retain(&mut self, mut keep: impl FnMut(&T) -> bool) -> Result<(), Self::Error> {
+        self.retain_mut(|element| keep(element))
+    }
+    fn retain_mut(&mut self, mut keep: impl FnMut(&mut T) -> bool) -> Result<(), Self::Error> {
+        // Swap the retained elements to the front so that `self` stays intact if `keep` panics
+        let mut retained = 0;
+        for index in 0 .. self.len() {
+            let slice = self.as_slice_mut();
+            if keep(&mut slice[index]) {
+                slice.swap(retained, index);
+                retained += 1;
+            }
+        }
+
+        // Pop the remaining elements
+        self.shrink(retained)
+    }
+    fn dedup(&mut self) -> Result<(), Self::Error> where T: PartialEq {
+        self.dedup_by(|element, previous| element == previous)
+    }
+    fn dedup_by(&mut self, mut same_bucket: impl FnMut(&mut T, &mut T) -> bool) -> Result<(), Self::Error> {
+        // Swap the retained elements to the front so that `self` stays intact if `same_bucket` panics
+        let mut retained = 1;
+        for index in 1 .. self.len() {
+            let (retained_slice, remaining) = self.as_slice_mut().split_at_mut(index);
+            if !same_bucket(&mut remaining[0], &mut retained_slice[retained - 1]) {
+                self.as_slice_mut().swap(retained, index);
+                retained += 1;
+            }
+        }
+
+        // Pop the remaining elements
+        self.shrink(retained)
+    }
+    fn dedup_by_key<Key>(&mut self, mut key: impl FnMut(&mut T) -> Key) -> Result<(), Self::Error>
+        where Key: PartialEq
+    {
+        self.dedup_by(|element, previous| key(element) == key(previous))
+    }
+
+    fn replace_range<Range, Source>(&mut self, range: Range, elements: &Source) -> Result<Option<()>, Self::Error>
+        where Range: RangeBounds<usize>, Source: ArrayRef<T>, T: Clone
+    {
+        // Validate the range
+        let len = self.len();
+        let range = match range.into_absolute(0, len) {
+            Some(range) if range.end <= len => range,
+            _ => return Ok(None)
+        };
+
+        // Shrink or grow the array in place
+        let (removed, elements) = (range.len(), elements.as_slice());
+        match elements.len().checked_sub(removed) {
+            Some(additional) => {
+                // Append the surplus elements first so that `self` stays intact if the allocation fails
+                if let Err(e) = self.wrapped.extend_from_slice(&elements[removed..]) {
+                    self.wrapped.truncate(len)?;
+                    Err(e)?;
+                }
+                self.as_slice_mut()[range.end..].rotate_right(additional);
+                self.as_slice_mut()[range.clone()].clone_from_slice(&elements[..removed]);
+            },
+            None => {
+                // Overwrite the leading elements and rotate the superfluous elements to the end
+                let superfluous = removed - elements.len();
+                self.as_slice_mut()[range.start .. range.start + elements.len()].clone_from_slice(elements);
+                self.as_slice_mut()[range.start + elements.len()..].rotate_left(superfluous);
+                self.wrapped.truncate(len - superfluous)?;
+            }
         }
+        Ok(Some(()))
+    }
 
-        // Reverse the order in the new array and return it
-        popped.reverse();
+    fn append(&mut self, other: &mut Self) -> Result<(), Self::Error> {
+        self.wrapped.append(&mut other.wrapped)
+    }
+}
+impl<T, Wrapped> ArrayAllocNew<T> for Array<Wrapped> where Wrapped: AsRef<[T]> + AsMut<[T]> + CanAllocNew<T> {
+    fn alloc_new() -> Result<Self, Self::Error> {
+        Ok(Self::new(Wrapped::alloc_new()?))
+    }
+    fn alloc_clone<Source>(elements: &Source) -> Result<Self, Self::Error> where Source: ArrayRef<T>, T: Clone {
+        let mut this = Self::alloc_new()?;
+        this.push_n_back(elements)?;
+        Ok(this)
+    }
+    fn alloc_from_iter<Elements>(elements: Elements) -> Result<Self, Self::Error>
+        where Elements: IntoIterator<Item = T>
+    {
+        let mut this = Self::alloc_new()?;
+        this.try_extend(elements).map_err(|(e, _)| e)?;
+        Ok(this)
+    }
+
+    fn pop_n_front(&mut self, len: usize) -> Result<Option<Self>, Self::Error> {
+        // Split off the remaining elements and keep them instead of the popped ones
+        let mut popped = match self.wrapped.split_off(len)? {
+            Some(remaining) => Self::new(remaining),
+            None => return Ok(None)
+        };
+        mem::swap(self, &mut popped);
         Ok(Some(popped))
     }
+    fn pop_n_back(&mut self, len: usize) -> Result<Option<Self>, Self::Error> {
+        // Don't mutate `self` if the operation cannot succeed
+        let at = match self.len().checked_sub(len) {
+            Some(at) => at,
+            None => return Ok(None)
+        };
+
+        let popped = self.wrapped.split_off(at)?;
+        Ok(popped.map(Self::new))
+    }
+
+    fn remove_n<Range>(&mut self, range: Range) -> Result<Option<Self>, Self::Error> where Range: RangeBounds<usize> {
+        // Don't mutate `self` if the operation cannot succeed
+        let len = self.len();
+        let range = match range.into_absolute(0, len) {
+            Some(range) if range.end <= len => range,
+            _ => return Ok(None)
+        };
+
+        // Rotate the elements to the end and split them off
+        let count = range.len();
+        self.as_slice_mut()[range.start..].rotate_left(count);
+        match self.wrapped.split_off(len - count) {
+            Ok(removed) => Ok(removed.map(Self::new)),
+            Err(e) => {
+                // Restore the original order
+                self.as_slice_mut()[range.start..].rotate_right(count);
+                Err(e)
+            }
+        }
+    }
+
+    fn split_off(&mut self, at: usize) -> Result<Option<Self>, Self::Error> {
+        let other = self.wrapped.split_off(at)?;
+        Ok(other.map(Self::new))
+    }
+}
+impl<T, Wrapped> ArraySegmentedRef<T> for Array<Wrapped> where Wrapped: AsSlices<T> {
+    fn as_slices(&self) -> (&[T], &[T]) {
+        self.wrapped.as_slices()
+    }
+    fn len(&self) -> usize {
+        let (front, back) = self.as_slices();
+        front.len() + back.len()
+    }
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn get(&self, index: usize) -> Option<&T> {
+        let (front, back) = self.as_slices();
+        match index.checked_sub(front.len()) {
+            Some(index) => back.get(index),
+            None => front.get(index)
+        }
+    }
+    fn get_n<Range>(&self, range: Range) -> Option<ArraySplit<'_, T>> where Range: RangeBounds<usize> {
+        // Translate the range into a range for each segment
+        let (front, back) = self.as_slices();
+        let range = range.into_absolute(0, front.len() + back.len())?;
+        let front_range = cmp::min(range.start, front.len()) .. cmp::min(range.end, front.len());
+        let back_range = range.start.saturating_sub(front.len()) .. range.end.saturating_sub(front.len());
+
+        // Validate the back range which also covers the end of the range
+        let back = back.get(back_range)?;
+        Some((Array::new(&front[front_range]), Array::new(back)))
+    }
+
+    fn first(&self) -> Option<&T> {
+        let (front, back) = self.as_slices();
+        front.first().or(back.first())
+    }
+    fn last(&self) -> Option<&T> {
+        let (front, back) = self.as_slices();
+        back.last().or(front.last())
+    }
+
+    fn iter(&self) -> Chain<SliceIter<'_, T>, SliceIter<'_, T>> {
+        let (front, back) = self.as_slices();
+        front.iter().chain(back.iter())
+    }
+}
+impl<T, Wrapped> ArraySegmentedMut<T> for Array<Wrapped> where Wrapped: AsSlices<T> {
+    fn as_slices_mut(&mut self) -> (&mut [T], &mut [T]) {
+        self.wrapped.as_slices_mut()
+    }
+
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        let (front, back) = self.as_slices_mut();
+        match index.checked_sub(front.len()) {
+            Some(index) => back.get_mut(index),
+            None => front.get_mut(index)
+        }
+    }
+    fn get_n_mut<Range>(&mut self, range: Range) -> Option<ArraySplitMut<'_, T>> where Range: RangeBounds<usize> {
+        // Translate the range into a range for each segment
+        let (front, back) = self.as_slices_mut();
+        let range = range.into_absolute(0, front.len() + back.len())?;
+        let front_range = cmp::min(range.start, front.len()) .. cmp::min(range.end, front.len());
+        let back_range = range.start.saturating_sub(front.len()) .. range.end.saturating_sub(front.len());
+
+        // Validate the back range which also covers the end of the range
+        let back = back.get_mut(back_range)?;
+        Some((Array::new(&mut front[front_range]), Array::new(back)))
+    }
+
+    fn first_mut(&mut self) -> Option<&mut T> {
+        let (front, back) = self.as_slices_mut();
+        front.first_mut().or(back.first_mut())
+    }
+    fn last_mut(&mut self) -> Option<&mut T> {
+        let (front, back) = self.as_slices_mut();
+        back.last_mut().or(front.last_mut())
+    }
+
+    fn iter_mut(&mut self) -> Chain<SliceIterMut<'_, T>, SliceIterMut<'_, T>> {
+        let (front, back) = self.as_slices_mut();
+        front.iter_mut().chain(back.iter_mut())
+    }
+}
+impl<T, Wrapped> ArraySegmentedAlloc<T> for Array<Wrapped> where Wrapped: CanAllocSegmented<T> {
+    type Error = Wrapped::Error;
+
+    fn grow_with(&mut self, len: usize, mut init: impl FnMut() -> T) -> Result<(), Self::Error> {
+        let additional = len.saturating_sub(self.len());
+        self.wrapped.reserve(additional)?;
+        for _ in 0 .. additional {
+            self.push_back(init())?;
+        }
+        Ok(())
+    }
+    fn grow(&mut self, len: usize) -> Result<(), Self::Error> where T: Default {
+        self.grow_with(len, T::default)
+    }
+    fn shrink(&mut self, len: usize) -> Result<(), Self::Error> {
+        self.wrapped.truncate(len)
+    }
+
+    fn push_front(&mut self, element: T) -> Result<(), Self::Error> {
+        self.wrapped.try_push_front(element).map_err(|(e, _)| e)
+    }
+    fn push_back(&mut self, element: T) -> Result<(), Self::Error> {
+        self.wrapped.try_push_back(element).map_err(|(e, _)| e)
+    }
+    fn pop_front(&mut self) -> Result<Option<T>, Self::Error> {
+        self.wrapped.pop_front()
+    }
+    fn pop_back(&mut self) -> Result<Option<T>, Self::Error> {
+        self.wrapped.pop_back()
+    }
 }
 // - MARK: Propagate common trait implementations
 impl<Wrapped> Debug for Array<Wrapped> where Wrapped: Debug {
@@ -248,4 +840,39 @@ impl<Wrapped> IntoIterator for Array<Wrapped> where Wrapped: IntoIterator {
     fn into_iter(self) -> Self::IntoIter {
         self.wrapped.into_iter()
     }
+}
+impl<'a, Wrapped> IntoIterator for &'a Array<Wrapped> where &'a Wrapped: IntoIterator {
+    type Item = <&'a Wrapped as IntoIterator>::Item;
+    type IntoIter = <&'a Wrapped as IntoIterator>::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        (&self.wrapped).into_iter()
+    }
+}
+impl<'a, Wrapped> IntoIterator for &'a mut Array<Wrapped> where &'a mut Wrapped: IntoIterator {
+    type Item = <&'a mut Wrapped as IntoIterator>::Item;
+    type IntoIter = <&'a mut Wrapped as IntoIterator>::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        (&mut self.wrapped).into_iter()
+    }
+}
+impl<T, Wrapped> FromIterator<T> for Array<Wrapped>
+    where Wrapped: AsRef<[T]> + AsMut<[T]> + CanAllocNew<T, Error = WillPanic>
+{
+    fn from_iter<Elements>(elements: Elements) -> Self where Elements: IntoIterator<Item = T> {
+        match Self::alloc_from_iter(elements) {
+            Ok(this) => this,
+            Err(e) => match e {}
+        }
+    }
+}
+impl<T, Wrapped> Extend<T> for Array<Wrapped>
+    where Wrapped: AsRef<[T]> + AsMut<[T]> + CanAlloc<T, Error = WillPanic>
+{
+    fn extend<Elements>(&mut self, elements: Elements) where Elements: IntoIterator<Item = T> {
+        if let Err((e, _)) = self.try_extend(elements) {
+            match e {}
+        }
+    }
 }
\ No newline at end of file
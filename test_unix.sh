@@ -2,9 +2,15 @@
 set -e
 
 # Test no_std builds
+cargo build --no-default-features --features=""
 cargo test --no-default-features --features=""
 cargo test --no-default-features --features="" --release
 
+# Test no_std builds with alloc
+cargo build --no-default-features --features="alloc"
+cargo test --no-default-features --features="alloc"
+cargo test --no-default-features --features="alloc" --release
+
 # Test std builds
 cargo test --no-default-features --features="std"
 cargo test --no-default-features --features="std" --release
\ No newline at end of file
@@ -0,0 +1,518 @@
+#![allow(dead_code)]
+
+use checked_array::{ Array, ArrayRef, ArrayAlloc, ArrayAllocNew, misc::ArrayError };
+
+
+/// The amount of operations per differential run
+pub const ROUNDS: usize = 4096;
+
+
+/// A xorshift pseudo random number generator
+pub struct Rng {
+    /// The current state
+    state: u64
+}
+impl Rng {
+    /// Creates a new generator with the given `seed`
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed | 1 }
+    }
+
+    /// The next random number
+    pub fn next(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state
+    }
+    /// A random number within `0..=max`
+    pub fn upto(&mut self, max: usize) -> usize {
+        (self.next() % (max as u64 + 1)) as usize
+    }
+    /// A random small element
+    pub fn element(&mut self) -> u8 {
+        self.upto(7) as u8
+    }
+    /// Random small elements with a length within `0..=max`
+    pub fn elements(&mut self, max: usize) -> Vec<u8> {
+        (0 .. self.upto(max)).map(|_| self.element()).collect()
+    }
+    /// A random, possibly invalid range around `0..len`
+    pub fn range(&mut self, len: usize) -> (usize, usize) {
+        (self.upto(len + 1), self.upto(len + 1))
+    }
+}
+
+
+/// A `Vec` with an optional capacity limit that serves as reference implementation
+pub struct Reference {
+    /// The reference elements
+    pub vec: Vec<u8>,
+    /// The capacity limit if any
+    pub capacity: Option<usize>
+}
+impl Reference {
+    /// Creates a new empty reference with the given capacity limit
+    pub fn new(capacity: Option<usize>) -> Self {
+        Self { vec: Vec::new(), capacity }
+    }
+
+    /// Whether the reference can hold `len` elements
+    pub fn fits(&self, len: usize) -> bool {
+        self.capacity.is_none_or(|capacity| len <= capacity)
+    }
+    /// The largest length that is interesting for `self`
+    pub fn max_len(&self) -> usize {
+        self.capacity.unwrap_or(self.vec.len() + 8) + 2
+    }
+    /// Whether `start..end` is a valid range within the reference
+    pub fn is_valid(&self, (start, end): (usize, usize)) -> bool {
+        start <= end && end <= self.vec.len()
+    }
+}
+
+
+/// Asserts that `result` is `Ok` if `expected` is `true` and `Err` otherwise
+fn check<T, E>(result: Result<T, E>, expected: bool, op: &str) -> Option<T> where E: std::fmt::Debug {
+    match result {
+        Ok(value) => {
+            assert!(expected, "{} succeeded unexpectedly", op);
+            Some(value)
+        },
+        Err(e) => {
+            assert!(!expected, "{} failed unexpectedly: {:?}", op, e);
+            None
+        }
+    }
+}
+
+
+/// A random operation that is applied to an array and to a reference
+pub type Op<A> = fn(&mut A, &mut Reference, &mut Rng);
+
+/// Applies a random `ArrayAlloc` operation to `array` and `reference` and compares the results
+pub fn apply<A>(array: &mut A, reference: &mut Reference, rng: &mut Rng) where A: ArrayAlloc<u8> {
+    let ops: &[Op<A>] = &[
+        push, pop, grow_shrink, rearrange, lookup,
+        insert_remove,
+        swap,
+        fill_range,
+        within,
+        drain,
+        retain_dedup,
+        try_extend,
+        replace_range,
+        resize,
+    ];
+    ops[rng.upto(ops.len() - 1)](array, reference, rng)
+}
+
+/// Applies a random `ArrayAllocNew` or `ArrayAlloc` operation to `array` and `reference` and compares the results
+pub fn apply_new<A>(array: &mut A, reference: &mut Reference, rng: &mut Rng) where A: ArrayAllocNew<u8> {
+    let ops: &[Op<A>] = &[
+        pop_n, alloc_clone,
+        remove_n,
+        split_off_append,
+    ];
+    match rng.upto(1) {
+        0 => ops[rng.upto(ops.len() - 1)](array, reference, rng),
+        _ => apply(array, reference, rng)
+    }
+}
+
+
+/// Pushes one or more elements to the front or the back
+fn push<A>(array: &mut A, reference: &mut Reference, rng: &mut Rng) where A: ArrayAlloc<u8> {
+    let len = reference.vec.len();
+    match rng.upto(3) {
+        0 => {
+            let element = rng.element();
+            if check(array.push_front(element), reference.fits(len + 1), "push_front").is_some() {
+                reference.vec.insert(0, element);
+            }
+        },
+        1 => {
+            let element = rng.element();
+            if check(array.push_back(element), reference.fits(len + 1), "push_back").is_some() {
+                reference.vec.push(element);
+            }
+        },
+        2 => {
+            let elements = rng.elements(4);
+            let expected = reference.fits(len + elements.len());
+            if check(array.push_n_front(&Array::new(elements.as_slice())), expected, "push_n_front").is_some() {
+                reference.vec.splice(0..0, elements);
+            }
+        },
+        _ => {
+            let elements = rng.elements(4);
+            let expected = reference.fits(len + elements.len());
+            if check(array.push_n_back(&Array::new(elements.as_slice())), expected, "push_n_back").is_some() {
+                reference.vec.extend(elements);
+            }
+        }
+    }
+}
+
+/// Pops an element from the front or the back
+fn pop<A>(array: &mut A, reference: &mut Reference, rng: &mut Rng) where A: ArrayAlloc<u8> {
+    match rng.upto(1) {
+        0 => {
+            let expected = match reference.vec.is_empty() {
+                true => None,
+                false => Some(reference.vec.remove(0))
+            };
+            assert_eq!(array.pop_front().expect("pop_front failed"), expected);
+        },
+        _ => assert_eq!(array.pop_back().expect("pop_back failed"), reference.vec.pop())
+    }
+}
+
+/// Grows or shrinks the array
+fn grow_shrink<A>(array: &mut A, reference: &mut Reference, rng: &mut Rng) where A: ArrayAlloc<u8> {
+    let len = reference.vec.len();
+    match rng.upto(1) {
+        0 => {
+            let new_len = rng.upto(reference.max_len());
+            if check(array.grow(new_len), new_len <= len || reference.fits(new_len), "grow").is_some() {
+                reference.vec.resize(len.max(new_len), 0);
+            }
+        },
+        _ => {
+            let new_len = rng.upto(len + 1);
+            array.shrink(new_len).expect("shrink failed");
+            reference.vec.truncate(new_len);
+        }
+    }
+}
+
+/// Rotates or reverses the elements
+fn rearrange<A>(array: &mut A, reference: &mut Reference, rng: &mut Rng) where A: ArrayAlloc<u8> {
+    let len = reference.vec.len();
+    match rng.upto(2) {
+        0 => {
+            let count = rng.upto(len + 1);
+            array.rotate_left(count);
+            if len > 0 {
+                reference.vec.rotate_left(count % len);
+            }
+        },
+        1 => {
+            let count = rng.upto(len + 1);
+            array.rotate_right(count);
+            if len > 0 {
+                reference.vec.rotate_right(count % len);
+            }
+        },
+        _ => {
+            array.reverse();
+            reference.vec.reverse();
+        }
+    }
+}
+
+/// Compares random elements and views
+fn lookup<A>(array: &mut A, reference: &mut Reference, rng: &mut Rng) where A: ArrayAlloc<u8> {
+    let len = reference.vec.len();
+    let (index, (start, end)) = (rng.upto(len), rng.range(len));
+    assert_eq!(array.get(index), reference.vec.get(index));
+    assert_eq!(array.first(), reference.vec.first());
+    assert_eq!(array.last(), reference.vec.last());
+    assert_eq!(array.get_n(start..end).map(|view| view.as_slice().to_vec()),
+        reference.vec.get(start..end).map(|slice| slice.to_vec()));
+}
+
+/// Inserts or removes elements at a random index
+fn insert_remove<A>(array: &mut A, reference: &mut Reference, rng: &mut Rng) where A: ArrayAlloc<u8> {
+    let len = reference.vec.len();
+    match rng.upto(3) {
+        0 => {
+            let (index, element) = (rng.upto(len + 1), rng.element());
+            let expected = index > len || reference.fits(len + 1);
+            match check(array.insert(index, element), expected, "insert") {
+                Some(Some(())) => reference.vec.insert(index, element),
+                Some(None) => assert!(index > len),
+                None => ()
+            }
+        },
+        1 => {
+            let (index, elements) = (rng.upto(len + 1), rng.elements(4));
+            let expected = index > len || reference.fits(len + elements.len());
+            match check(array.insert_n(index, &Array::new(elements.as_slice())), expected, "insert_n") {
+                Some(Some(())) => drop(reference.vec.splice(index..index, elements)),
+                Some(None) => assert!(index > len),
+                None => ()
+            }
+        },
+        2 => {
+            let index = rng.upto(len);
+            let expected = (index < len).then(|| reference.vec.remove(index));
+            assert_eq!(array.remove(index).expect("remove failed"), expected);
+        },
+        _ => {
+            let index = rng.upto(len);
+            let expected = (index < len).then(|| reference.vec.swap_remove(index));
+            assert_eq!(array.swap_remove(index).expect("swap_remove failed"), expected);
+        }
+    }
+}
+
+/// Swaps two random elements
+fn swap<A>(array: &mut A, reference: &mut Reference, rng: &mut Rng) where A: ArrayAlloc<u8> {
+    let len = reference.vec.len();
+    let (a, b) = (rng.upto(len), rng.upto(len));
+    match a < len && b < len {
+        true => {
+            array.swap(a, b).expect("swap failed");
+            reference.vec.swap(a, b);
+        },
+        false => assert!(array.swap(a, b).is_none())
+    }
+}
+
+/// Fills a random range with a random element
+fn fill_range<A>(array: &mut A, reference: &mut Reference, rng: &mut Rng) where A: ArrayAlloc<u8> {
+    let ((start, end), value) = (rng.range(reference.vec.len()), rng.element());
+    match reference.is_valid((start, end)) {
+        true => {
+            array.fill_range(start..end, value).expect("fill_range failed");
+            reference.vec[start..end].fill(value);
+        },
+        false => assert!(array.fill_range(start..end, value).is_none())
+    }
+}
+
+/// Copies a random range within the array or appends it
+fn within<A>(array: &mut A, reference: &mut Reference, rng: &mut Rng) where A: ArrayAlloc<u8> {
+    let len = reference.vec.len();
+    match rng.upto(1) {
+        0 => {
+            let ((start, end), dest) = (rng.range(len), rng.upto(len));
+            match reference.is_valid((start, end)) && dest + (end - start) <= len {
+                true => {
+                    array.copy_within(start..end, dest).expect("copy_within failed");
+                    reference.vec.copy_within(start..end, dest);
+                },
+                false => assert!(array.copy_within(start..end, dest).is_none())
+            }
+        },
+        _ => {
+            let (start, end) = rng.range(len);
+            let valid = reference.is_valid((start, end));
+            let expected = !valid || reference.fits(len + (end - start));
+            match check(array.extend_from_within(start..end), expected, "extend_from_within") {
+                Some(Some(())) => reference.vec.extend_from_within(start..end),
+                Some(None) => assert!(!valid),
+                None => ()
+            }
+        }
+    }
+}
+
+/// Drains a random range completely or partially
+fn drain<A>(array: &mut A, reference: &mut Reference, rng: &mut Rng) where A: ArrayAlloc<u8> {
+    let (start, end) = rng.range(reference.vec.len());
+    match (reference.is_valid((start, end)), rng.upto(1)) {
+        (true, 0) => {
+            let drained: Vec<_> = array.drain(start..end).expect("drain failed").collect();
+            assert_eq!(drained, reference.vec.drain(start..end).collect::<Vec<_>>());
+        },
+        (true, _) => {
+            // Consume only a part of the drained elements
+            let take = rng.upto(end - start);
+            let drained: Vec<_> = array.drain(start..end).expect("drain failed").take(take).collect();
+            assert_eq!(drained, reference.vec.drain(start..end).take(take).collect::<Vec<_>>());
+        },
+        (false, _) => assert!(array.drain(start..end).is_none())
+    }
+}
+
+/// Retains or deduplicates the elements
+fn retain_dedup<A>(array: &mut A, reference: &mut Reference, rng: &mut Rng) where A: ArrayAlloc<u8> {
+    match rng.upto(3) {
+        0 => {
+            let limit = rng.element();
+            array.retain(|element| *element < limit).expect("retain failed");
+            reference.vec.retain(|element| *element < limit);
+        },
+        1 => {
+            let modulus = rng.upto(3) as u8 + 2;
+            let keep = |element: &mut u8| {
+                *element = (*element + 1) % 8;
+                !(*element).is_multiple_of(modulus)
+            };
+            array.retain_mut(keep).expect("retain_mut failed");
+            reference.vec.retain_mut(keep);
+        },
+        2 => {
+            array.dedup().expect("dedup failed");
+            reference.vec.dedup();
+        },
+        _ => {
+            array.dedup_by_key(|element| *element / 2).expect("dedup_by_key failed");
+            reference.vec.dedup_by_key(|element| *element / 2);
+        }
+    }
+}
+
+/// Extends the array from an iterator
+fn try_extend<A>(array: &mut A, reference: &mut Reference, rng: &mut Rng) where A: ArrayAlloc<u8> {
+    let (len, elements) = (reference.vec.len(), rng.elements(6));
+    match rng.upto(1) {
+        0 => {
+            // Push the elements until the capacity is exhausted
+            let free = reference.capacity.map_or(elements.len(), |capacity| capacity - len);
+            let accepted = free.min(elements.len());
+            match array.try_extend(elements.iter().copied()) {
+                Ok(()) => assert_eq!(accepted, elements.len(), "try_extend succeeded unexpectedly"),
+                Err((_, rejected)) => assert_eq!(Some(&rejected), elements.get(accepted))
+            }
+            reference.vec.extend_from_slice(&elements[..accepted]);
+        },
+        _ => {
+            let expected = reference.fits(len + elements.len());
+            if check(array.try_extend_all(elements.iter().copied()), expected, "try_extend_all").is_some() {
+                reference.vec.extend(elements);
+            }
+        }
+    }
+}
+
+/// Replaces a random range with random elements
+fn replace_range<A>(array: &mut A, reference: &mut Reference, rng: &mut Rng) where A: ArrayAlloc<u8> {
+    let len = reference.vec.len();
+    let ((start, end), elements) = (rng.range(len), rng.elements(4));
+    let valid = reference.is_valid((start, end));
+    let expected = !valid || reference.fits(len - (end - start).min(len) + elements.len());
+    let result = array.replace_range(start..end, &Array::new(elements.as_slice()));
+    match check(result, expected, "replace_range") {
+        Some(Some(())) => drop(reference.vec.splice(start..end, elements)),
+        Some(None) => assert!(!valid),
+        None => ()
+    }
+}
+
+/// Resizes the array, or grows or shrinks it to an exact length
+fn resize<A>(array: &mut A, reference: &mut Reference, rng: &mut Rng) where A: ArrayAlloc<u8> {
+    let len = reference.vec.len();
+    match rng.upto(2) {
+        0 => {
+            let (new_len, value) = (rng.upto(reference.max_len()), rng.element());
+            if check(array.resize(new_len, value), new_len <= len || reference.fits(new_len), "resize").is_some() {
+                reference.vec.resize(new_len, value);
+            }
+        },
+        1 => {
+            let new_len = rng.upto(reference.max_len());
+            match array.grow_exact(new_len) {
+                Ok(()) => {
+                    assert!(new_len >= len && reference.fits(new_len), "grow_exact succeeded unexpectedly");
+                    reference.vec.resize(new_len, 0);
+                },
+                Err(ArrayError::InvalidLength { .. }) => assert!(new_len < len),
+                Err(ArrayError::Alloc(_)) => assert!(!reference.fits(new_len)),
+                Err(e) => panic!("grow_exact failed unexpectedly: {:?}", e)
+            }
+        },
+        _ => {
+            let new_len = rng.upto(len + 1);
+            match array.shrink_exact(new_len) {
+                Ok(()) => reference.vec.truncate(new_len),
+                Err(ArrayError::InvalidLength { .. }) => assert!(new_len > len),
+                Err(e) => panic!("shrink_exact failed unexpectedly: {:?}", e)
+            }
+        }
+    }
+}
+
+
+/// Pops multiple elements from the front or the back
+fn pop_n<A>(array: &mut A, reference: &mut Reference, rng: &mut Rng) where A: ArrayAllocNew<u8> {
+    let len = reference.vec.len();
+    let count = rng.upto(len + 1);
+    match rng.upto(1) {
+        0 => {
+            let popped = array.pop_n_front(count).expect("pop_n_front failed");
+            let expected = (count <= len).then(|| reference.vec.drain(..count).collect::<Vec<_>>());
+            assert_eq!(popped.map(|popped| popped.as_slice().to_vec()), expected);
+        },
+        _ => {
+            let popped = array.pop_n_back(count).expect("pop_n_back failed");
+            let expected = (count <= len).then(|| reference.vec.split_off(len - count));
+            assert_eq!(popped.map(|popped| popped.as_slice().to_vec()), expected);
+        }
+    }
+}
+
+/// Replaces the array with a clone of random elements
+fn alloc_clone<A>(array: &mut A, reference: &mut Reference, rng: &mut Rng) where A: ArrayAllocNew<u8> {
+    let elements = rng.elements(reference.max_len());
+    if let Some(clone) = check(A::alloc_clone(&Array::new(elements.as_slice())), reference.fits(elements.len()),
+        "alloc_clone")
+    {
+        *array = clone;
+        reference.vec = elements;
+    }
+}
+
+/// Removes a random range
+fn remove_n<A>(array: &mut A, reference: &mut Reference, rng: &mut Rng) where A: ArrayAllocNew<u8> {
+    let (start, end) = rng.range(reference.vec.len());
+    let removed = array.remove_n(start..end).expect("remove_n failed");
+    let expected = reference.is_valid((start, end)).then(|| reference.vec.drain(start..end).collect::<Vec<_>>());
+    assert_eq!(removed.map(|removed| removed.as_slice().to_vec()), expected);
+}
+
+/// Splits the array at a random index or appends another array
+fn split_off_append<A>(array: &mut A, reference: &mut Reference, rng: &mut Rng) where A: ArrayAllocNew<u8> {
+    let len = reference.vec.len();
+    match rng.upto(1) {
+        0 => {
+            let at = rng.upto(len + 1);
+            let split = array.split_off(at).expect("split_off failed");
+            let expected = (at <= len).then(|| reference.vec.split_off(at));
+            assert_eq!(split.map(|split| split.as_slice().to_vec()), expected);
+        },
+        _ => {
+            // Create an `other` array that can hold the elements
+            let mut elements = rng.elements(6);
+            elements.truncate(reference.capacity.unwrap_or(elements.len()));
+            let mut other = A::alloc_clone(&Array::new(elements.as_slice())).expect("alloc_clone failed");
+
+            match check(array.append(&mut other), reference.fits(len + elements.len()), "append") {
+                Some(()) => {
+                    assert!(other.is_empty());
+                    reference.vec.extend(elements);
+                },
+                None => assert_eq!(other.as_slice(), elements.as_slice())
+            }
+        }
+    }
+}
+
+
+/// Compares `array` with `reference`
+pub fn compare<A>(array: &A, reference: &Reference) where A: ArrayRef<u8> {
+    assert_eq!(array.len(), reference.vec.len());
+    assert_eq!(array.as_slice(), reference.vec.as_slice());
+}
+
+/// Applies `ROUNDS` random operations to `array` and to a reference `Vec` with the given capacity limit and compares
+/// them after each operation
+pub fn differential<A>(mut array: A, capacity: Option<usize>, seed: u64) where A: ArrayAlloc<u8> {
+    let (mut reference, mut rng) = (Reference::new(capacity), Rng::new(seed));
+    for _ in 0 .. ROUNDS {
+        apply(&mut array, &mut reference, &mut rng);
+        compare(&array, &reference);
+    }
+}
+
+/// Like `differential`, but also applies the `ArrayAllocNew` operations
+pub fn differential_new<A>(capacity: Option<usize>, seed: u64) where A: ArrayAllocNew<u8> {
+    let mut array = A::alloc_new().expect("alloc_new failed");
+    let (mut reference, mut rng) = (Reference::new(capacity), Rng::new(seed));
+    for _ in 0 .. ROUNDS {
+        apply_new(&mut array, &mut reference, &mut rng);
+        compare(&array, &reference);
+    }
+}
\ No newline at end of file
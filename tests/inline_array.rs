@@ -0,0 +1,29 @@
+mod common;
+
+use checked_array::{ Array, ArrayRef, ArrayAllocPanic, ArrayAllocNewPanic, InlineArray };
+use std::rc::Rc;
+
+
+#[test]
+fn differential() {
+    common::differential_new::<Array<InlineArray<u8, 0>>>(Some(0), 1);
+    common::differential_new::<Array<InlineArray<u8, 1>>>(Some(1), 2);
+    common::differential_new::<Array<InlineArray<u8, 5>>>(Some(5), 3);
+    common::differential_new::<Array<InlineArray<u8, 16>>>(Some(16), 4);
+}
+
+#[test]
+fn drops() {
+    let element = Rc::new(());
+    {
+        let mut array: Array<InlineArray<Rc<()>, 8>> = Array::alloc_new();
+        (0 .. 8).for_each(|_| array.push_back(element.clone()));
+        let split = array.split_off(5).expect("Failed to split within bounds?!");
+        let popped = array.pop_n_front(2).expect("Failed to pop within bounds?!");
+        assert_eq!((array.len(), split.len(), popped.len()), (3, 3, 2));
+        assert_eq!(Rc::strong_count(&element), 9);
+    }
+
+    // Dropping the arrays must drop the elements exactly once
+    assert_eq!(Rc::strong_count(&element), 1);
+}
\ No newline at end of file
@@ -0,0 +1,74 @@
+use checked_array::{ Array, ArrayRef, ArrayMut, ArrayAllocPanic, Preallocated, PreallocatedUninit, InlineArray };
+#[cfg(feature = "alloc")]
+use checked_array::{ TryVec, SmallArray };
+use std::mem::MaybeUninit;
+
+
+/// Fills `$array` with `1, 2, 3`, increments each element by reference and sums them up by reference
+macro_rules! iterate {
+    ($array:expr) => {{
+        let mut array = $array;
+        (1..=3).for_each(|element: u32| array.push_back(element));
+        for element in &mut array {
+            *element += 1;
+        }
+
+        let mut sum = 0;
+        for element in &array {
+            sum += *element;
+        }
+        sum
+    }};
+}
+
+
+#[test]
+#[cfg(feature = "alloc")]
+fn vec() {
+    assert_eq!(iterate!(Array::new(Vec::new())), 9);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn tryvec() {
+    assert_eq!(iterate!(Array::new(TryVec::new())), 9);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn small_array() {
+    assert_eq!(iterate!(Array::new(SmallArray::<u32, 2>::new())), 9);
+}
+
+#[test]
+fn inline_array() {
+    assert_eq!(iterate!(Array::new(InlineArray::<u32, 4>::new())), 9);
+}
+
+#[test]
+fn preallocated() {
+    assert_eq!(iterate!(Array::new(Preallocated::new([0; 4]))), 9);
+
+    let mut buffer = [0; 4];
+    assert_eq!(iterate!(Array::new(Preallocated::new(&mut buffer[..]))), 9);
+}
+
+#[test]
+fn preallocated_uninit() {
+    let buffer = [MaybeUninit::uninit(); 4];
+    assert_eq!(iterate!(Array::new(PreallocatedUninit::new(buffer))), 9);
+}
+
+#[test]
+fn views() {
+    let mut array = Array::new([1u32, 2, 3]);
+    for element in array.get_n_mut(1..).expect("Failed to get view?!") {
+        *element += 1;
+    }
+
+    let mut sum = 0;
+    for element in array.get_n(1..).expect("Failed to get view?!") {
+        sum += *element;
+    }
+    assert_eq!(sum, 7);
+}
\ No newline at end of file
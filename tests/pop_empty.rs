@@ -0,0 +1,52 @@
+use checked_array::{ Array, ArrayAlloc, Preallocated, PreallocatedUninit, InlineArray };
+#[cfg(feature = "alloc")]
+use checked_array::{ TryVec, SmallArray };
+use std::mem::MaybeUninit;
+
+
+/// Pops from the front and the back of the empty `$array` and ensures that nothing is popped
+macro_rules! pop_empty {
+    ($array:expr) => {{
+        let mut array = $array;
+        assert_eq!(ArrayAlloc::<u8>::pop_front(&mut array).expect("Failed to pop from empty array?!"), None);
+        assert_eq!(ArrayAlloc::<u8>::pop_back(&mut array).expect("Failed to pop from empty array?!"), None);
+    }};
+}
+
+
+#[test]
+#[cfg(feature = "alloc")]
+fn vec() {
+    pop_empty!(Array::new(Vec::new()));
+
+    let mut vec = Vec::new();
+    pop_empty!(Array::new(&mut vec));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn tryvec() {
+    pop_empty!(Array::new(TryVec::new()));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn small_array() {
+    pop_empty!(Array::new(SmallArray::<u8, 4>::new()));
+}
+
+#[test]
+fn inline_array() {
+    pop_empty!(Array::new(InlineArray::<u8, 4>::new()));
+}
+
+#[test]
+fn preallocated() {
+    pop_empty!(Array::new(Preallocated::new([0; 4])));
+    pop_empty!(Array::new(Preallocated::new([0; 0])));
+}
+
+#[test]
+fn preallocated_uninit() {
+    pop_empty!(Array::new(PreallocatedUninit::new([MaybeUninit::uninit(); 4])));
+}
\ No newline at end of file
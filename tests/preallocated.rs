@@ -0,0 +1,41 @@
+mod common;
+
+use checked_array::{ Array, ArrayRef, ArrayAllocPanic, Preallocated, misc::BufferTooSmall };
+
+
+#[test]
+fn differential() {
+    common::differential(Array::new(Preallocated::new([0; 0])), Some(0), 1);
+    common::differential(Array::new(Preallocated::new([0; 1])), Some(1), 2);
+    common::differential(Array::new(Preallocated::new([0; 5])), Some(5), 3);
+
+    let mut buffer = [0; 16];
+    common::differential(Array::new(Preallocated::new(&mut buffer[..])), Some(16), 4);
+}
+
+#[test]
+fn into_inner_after_pop_front() {
+    let popped = || {
+        let mut array = Array::new(Preallocated::new([0u8; 4]));
+        (1..=4).for_each(|element| array.push_back(element));
+        assert_eq!(array.pop_front(), Some(1));
+        assert_eq!(array.pop_front(), Some(2));
+        assert_eq!(array.len(), 2);
+        array.into_inner()
+    };
+
+    // The used elements start at the head offset of the returned buffer unless it is compacted
+    assert_eq!(popped().into_inner(), [0, 0, 3, 4]);
+    assert_eq!(popped().into_compacted(), [3, 4, 0, 0]);
+}
+
+#[test]
+fn buffer_too_small() {
+    let mut array = Array::new(Preallocated::new([0u8; 4]));
+    (1..=4).for_each(|element| array.push_back(element));
+    assert_eq!(array.pop_front(), Some(1));
+
+    // The error must describe the needed and available amount of elements
+    let result = checked_array::ArrayAlloc::push_n_back(&mut array, &Array::new([5, 6]));
+    assert_eq!(result, Err(BufferTooSmall { needed: 5, available: 4 }));
+}
\ No newline at end of file
@@ -0,0 +1,51 @@
+mod common;
+
+use checked_array::{ Array, ArrayRef, ArrayAllocPanic, PreallocatedUninit, misc::BufferTooSmall };
+use std::{ mem::MaybeUninit, rc::Rc };
+
+
+#[test]
+#[cfg(feature = "alloc")]
+fn differential() {
+    for (capacity, seed) in [(0, 1), (1, 2), (5, 3), (16, 4)] {
+        let buffer = vec![MaybeUninit::uninit(); capacity].into_boxed_slice();
+        common::differential(Array::new(PreallocatedUninit::new(buffer)), Some(capacity), seed);
+    }
+}
+
+#[test]
+fn differential_array() {
+    let buffer = [MaybeUninit::uninit(); 8];
+    common::differential(Array::new(PreallocatedUninit::new(buffer)), Some(8), 5);
+}
+
+#[test]
+fn drops() {
+    let element = Rc::new(());
+    {
+        let buffer: [MaybeUninit<Rc<()>>; 8] = [(); 8].map(|_| MaybeUninit::uninit());
+        let mut array = Array::new(PreallocatedUninit::new(buffer));
+        (0 .. 8).for_each(|_| array.push_back(element.clone()));
+        assert_eq!(Rc::strong_count(&element), 9);
+
+        // Pop and truncate some elements
+        drop(array.pop_front());
+        drop(array.pop_back());
+        array.shrink(3);
+        assert_eq!(array.len(), 3);
+        assert_eq!(Rc::strong_count(&element), 4);
+    }
+
+    // Dropping the array must drop the remaining elements exactly once
+    assert_eq!(Rc::strong_count(&element), 1);
+}
+
+#[test]
+fn buffer_too_small() {
+    let mut array = Array::new(PreallocatedUninit::new([MaybeUninit::uninit(); 2]));
+    (1..=2).for_each(|element: u8| array.push_back(element));
+
+    // The error must describe the needed and available amount of elements
+    assert_eq!(checked_array::ArrayAlloc::push_back(&mut array, 3), Err(BufferTooSmall { needed: 3, available: 2 }));
+    assert_eq!(checked_array::ArrayAlloc::grow(&mut array, 7), Err(BufferTooSmall { needed: 7, available: 2 }));
+}
\ No newline at end of file
@@ -0,0 +1,146 @@
+mod common;
+
+use checked_array::{
+    Array, ArrayRef, ArraySegmentedRef, ArraySegmentedMut, ArraySegmentedAlloc, RingBuffer, FullPolicy,
+    misc::BufferTooSmall
+};
+use common::Rng;
+use std::collections::VecDeque;
+
+
+/// Applies `common::ROUNDS` random operations to a ring buffer with the given `capacity` and `policy` and to a
+/// reference `VecDeque`, and compares them after each operation
+fn differential(capacity: usize, policy: FullPolicy, seed: u64) {
+    let mut array = Array::new(RingBuffer::new(vec![0u8; capacity], policy));
+    let (mut reference, mut rng) = (VecDeque::new(), Rng::new(seed));
+    let overwrite = policy == FullPolicy::Overwrite && capacity > 0;
+
+    for _ in 0 .. common::ROUNDS {
+        let len = reference.len();
+        let full = Err(BufferTooSmall { needed: len + 1, available: capacity });
+        match rng.upto(9) {
+            0 => {
+                let element = rng.element();
+                match (len < capacity, overwrite) {
+                    (true, _) => reference.push_back(element),
+                    (false, true) => {
+                        reference.pop_front();
+                        reference.push_back(element);
+                    },
+                    (false, false) => {
+                        assert_eq!(array.push_back(element), full);
+                        continue;
+                    }
+                }
+                array.push_back(element).expect("push_back failed");
+            },
+            1 => {
+                let element = rng.element();
+                match (len < capacity, overwrite) {
+                    (true, _) => reference.push_front(element),
+                    (false, true) => {
+                        reference.pop_back();
+                        reference.push_front(element);
+                    },
+                    (false, false) => {
+                        assert_eq!(array.push_front(element), full);
+                        continue;
+                    }
+                }
+                array.push_front(element).expect("push_front failed");
+            },
+            2 => assert_eq!(array.pop_front().expect("pop_front failed"), reference.pop_front()),
+            3 => assert_eq!(array.pop_back().expect("pop_back failed"), reference.pop_back()),
+            4 => {
+                let new_len = rng.upto(capacity + 2);
+                match new_len <= len || new_len <= capacity {
+                    true => {
+                        array.grow(new_len).expect("grow failed");
+                        reference.resize(len.max(new_len), 0);
+                    },
+                    false => {
+                        assert_eq!(array.grow(new_len), Err(BufferTooSmall { needed: new_len, available: capacity }));
+                    }
+                }
+            },
+            5 => {
+                let new_len = rng.upto(len + 1);
+                array.shrink(new_len).expect("shrink failed");
+                reference.truncate(new_len);
+            },
+            6 => {
+                let (index, element) = (rng.upto(len), rng.element());
+                match array.get_mut(index) {
+                    Some(slot) => *slot = element,
+                    None => assert!(index >= len)
+                }
+                if let Some(slot) = reference.get_mut(index) {
+                    *slot = element;
+                }
+            },
+            7 => {
+                let (start, end) = rng.range(len);
+                let split = array.get_n(start..end).map(|(front, back)| [front.as_slice(), back.as_slice()].concat());
+                let expected = (start <= end && end <= len).then(|| reference.range(start..end).copied().collect());
+                assert_eq!(split, expected);
+            },
+            _ => {
+                let index = rng.upto(len);
+                assert_eq!(array.get(index), reference.get(index));
+                assert_eq!(array.first(), reference.front());
+                assert_eq!(array.last(), reference.back());
+            }
+        }
+
+        assert_eq!(array.len(), reference.len());
+        assert!(array.iter().eq(reference.iter()));
+        assert_eq!(array.is_full(), reference.len() == capacity);
+    }
+}
+
+
+#[test]
+fn differential_reject() {
+    for (capacity, seed) in [(0, 1), (1, 2), (3, 3), (8, 4)] {
+        differential(capacity, FullPolicy::Reject, seed);
+    }
+}
+
+#[test]
+fn differential_overwrite() {
+    for (capacity, seed) in [(0, 5), (1, 6), (3, 7), (8, 8)] {
+        differential(capacity, FullPolicy::Overwrite, seed);
+    }
+}
+
+
+#[test]
+fn grow_beyond_capacity() {
+    for policy in [FullPolicy::Reject, FullPolicy::Overwrite] {
+        let mut array = Array::new(RingBuffer::new([0u8; 3], policy));
+        array.push_back(7).expect("Failed to push to empty ring buffer?!");
+
+        // Growing beyond the capacity must fail without touching the elements
+        assert_eq!(array.grow(10), Err(BufferTooSmall { needed: 10, available: 3 }));
+        assert_eq!(array.iter().copied().collect::<Vec<_>>(), [7]);
+
+        // Growing within the capacity must succeed
+        array.grow(3).expect("Failed to grow within capacity?!");
+        assert_eq!(array.iter().copied().collect::<Vec<_>>(), [7, 0, 0]);
+    }
+}
+
+#[test]
+fn zero_sized_max_capacity() {
+    let mut array = Array::new(RingBuffer::new(vec![(); usize::MAX], FullPolicy::Reject));
+    array.push_front(()).expect("Failed to push to empty ring buffer?!");
+    array.push_back(()).expect("Failed to push to non-full ring buffer?!");
+    array.push_back(()).expect("Failed to push to non-full ring buffer?!");
+    assert_eq!(array.len(), 3);
+
+    // Popping must not overflow the index computation either
+    assert_eq!(array.pop_back(), Ok(Some(())));
+    assert_eq!(array.pop_front(), Ok(Some(())));
+    assert_eq!(array.pop_back(), Ok(Some(())));
+    assert_eq!(array.pop_back(), Ok(None));
+}
\ No newline at end of file
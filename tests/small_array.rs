@@ -0,0 +1,36 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use checked_array::{ Array, ArrayRef, ArrayAllocPanic, ArrayAllocNewPanic, SmallArray };
+use std::rc::Rc;
+
+
+#[test]
+fn differential() {
+    common::differential_new::<Array<SmallArray<u8, 0>>>(None, 1);
+    common::differential_new::<Array<SmallArray<u8, 1>>>(None, 2);
+    common::differential_new::<Array<SmallArray<u8, 5>>>(None, 3);
+    common::differential_new::<Array<SmallArray<u8, 16>>>(None, 4);
+}
+
+#[test]
+fn drops() {
+    let element = Rc::new(());
+    {
+        let mut array: Array<SmallArray<Rc<()>, 4>> = Array::alloc_new();
+        (0 .. 4).for_each(|_| array.push_back(element.clone()));
+        assert!(array.is_inline());
+
+        // Spill to the heap and split the array up again
+        (0 .. 4).for_each(|_| array.push_front(element.clone()));
+        assert!(!array.is_inline());
+        let split = array.split_off(5).expect("Failed to split within bounds?!");
+        let popped = array.pop_n_back(2).expect("Failed to pop within bounds?!");
+        assert_eq!((array.len(), split.len(), popped.len()), (3, 3, 2));
+        assert_eq!(Rc::strong_count(&element), 9);
+    }
+
+    // Dropping the arrays must drop the elements exactly once
+    assert_eq!(Rc::strong_count(&element), 1);
+}
\ No newline at end of file